@@ -0,0 +1,130 @@
+//! Run commitment shared with `zk/cubeathon_circuit`.
+//!
+//! ```text
+//! outcome_hash = Poseidon.hash_5(domain, seed, player_id, time_ms, nonce)
+//! domain       = SHA-256(network_id ‖ contract.to_xdr() ‖ circuit_version) with the top 3 bits cleared
+//! player_id    = SHA-256(player.to_xdr()) with the top 3 bits cleared
+//! ```
+//!
+//! Each level is played on its own track, so the circuit's `seed` is the
+//! level seed (see `level_seed`), not the session seed itself. The level
+//! seed also covers the session's game mode and difficulty, so a proof made
+//! under one rule set never counts under another.
+//!
+//! `domain` is also a public input of the circuit. It ties every journal to
+//! one network, one Cubeathon deployment and one circuit version, so a proof
+//! made for testnet or for another deployment never matches here.
+//!
+//! The session `seed` is drawn by `start_game` (see `session_seed`) or, in
+//! commit-reveal mode, derived from both players' entropy. Clearing
+//! the top bits keeps `seed` and `player_id` below the BN254 scalar modulus,
+//! so clients can pass them to the circuit as `Field`s without reduction.
+
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, U256};
+
+use crate::{poseidon, Difficulty, GameMode};
+
+/// BN254 scalar field modulus r, big-endian.
+const FR_MODULUS_BE: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// Returns true if `value` is a canonical BN254 scalar, i.e. one the circuit
+/// would see unchanged as a `Field`.
+pub fn is_canonical(value: &BytesN<32>) -> bool {
+    value.to_array() < FR_MODULUS_BE
+}
+
+/// Clear the top 3 bits of a 256-bit digest so it is a canonical BN254 scalar.
+fn to_field(env: &Env, mut digest: [u8; 32]) -> BytesN<32> {
+    digest[0] &= 0x1f;
+    BytesN::from_array(env, &digest)
+}
+
+/// Per-session track seed: the PRNG output mixed with the session id and the
+/// current ledger, so that it is unique per session even for repeated PRNG
+/// states in tests.
+pub fn session_seed(env: &Env, session_id: u32) -> BytesN<32> {
+    let entropy: BytesN<32> = env.prng().gen();
+    let mut preimage = Bytes::from(entropy);
+    preimage.extend_from_array(&session_id.to_be_bytes());
+    preimage.extend_from_array(&env.ledger().sequence().to_be_bytes());
+    preimage.extend_from_array(&env.ledger().timestamp().to_be_bytes());
+    to_field(env, env.crypto().sha256(&preimage).to_array())
+}
+
+/// Track seed for one level: the circuit's `seed` input for runs on `level`.
+///
+/// ```text
+/// level_seed = SHA-256(session_seed ‖ mode ‖ difficulty ‖ level), each a u32 BE
+/// ```
+pub fn level_seed(
+    env: &Env,
+    session_seed: &BytesN<32>,
+    mode: GameMode,
+    difficulty: Difficulty,
+    level: u32,
+) -> BytesN<32> {
+    let mut preimage = Bytes::from(session_seed.clone());
+    preimage.extend_from_array(&(mode as u32).to_be_bytes());
+    preimage.extend_from_array(&(difficulty as u32).to_be_bytes());
+    preimage.extend_from_array(&level.to_be_bytes());
+    to_field(env, env.crypto().sha256(&preimage).to_array())
+}
+
+/// Fresh session seed after a tied round is replayed.
+pub fn replay_seed(env: &Env, seed: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = Bytes::from_slice(env, b"replay");
+    preimage.append(&seed.clone().into());
+    to_field(env, env.crypto().sha256(&preimage).to_array())
+}
+
+/// Seed for a commit-reveal session, derived from both players' entropy.
+pub fn revealed_seed(
+    env: &Env,
+    session_id: u32,
+    p1_entropy: &BytesN<32>,
+    p2_entropy: &BytesN<32>,
+) -> BytesN<32> {
+    let mut preimage = Bytes::from(p1_entropy.clone());
+    preimage.append(&p2_entropy.clone().into());
+    preimage.extend_from_array(&session_id.to_be_bytes());
+    to_field(env, env.crypto().sha256(&preimage).to_array())
+}
+
+/// Domain separator the circuit receives as its `domain` public input.
+pub fn journal_domain(env: &Env, circuit_version: u32) -> BytesN<32> {
+    let mut preimage = Bytes::from(env.ledger().network_id());
+    preimage.append(&env.current_contract_address().to_xdr(env));
+    preimage.extend_from_array(&circuit_version.to_be_bytes());
+    to_field(env, env.crypto().sha256(&preimage).to_array())
+}
+
+/// Field element the circuit receives as `player_id`.
+pub fn player_id(env: &Env, player: &Address) -> U256 {
+    let digest = to_field(env, env.crypto().sha256(&player.clone().to_xdr(env)).to_array());
+    U256::from_be_bytes(env, &digest.into())
+}
+
+/// Commitment the proof must expose as its `outcome_hash` public input.
+pub fn outcome_hash(
+    env: &Env,
+    domain: &BytesN<32>,
+    seed: &BytesN<32>,
+    player: &Address,
+    time_ms: u64,
+    nonce: &BytesN<32>,
+) -> BytesN<32> {
+    let digest = poseidon::hash_5(
+        env,
+        [
+            U256::from_be_bytes(env, &domain.clone().into()),
+            U256::from_be_bytes(env, &seed.clone().into()),
+            player_id(env, player),
+            U256::from_u128(env, time_ms as u128),
+            U256::from_be_bytes(env, &nonce.clone().into()),
+        ],
+    );
+    digest.to_be_bytes().try_into().unwrap()
+}
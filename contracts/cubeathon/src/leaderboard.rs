@@ -0,0 +1,374 @@
+//! Leaderboard storage.
+//!
+//! A board is a ranked list of `LeaderboardEntry`s split into buckets of at
+//! most `BUCKET_SIZE` entries, each in its own persistent entry. A
+//! `BoardIndex` lists the buckets in rank order with their length and the
+//! score of their last entry:
+//!
+//! ```text
+//! Leaderboard(board)            -> BoardIndex { buckets: [b0, b3, b1, ...], len }
+//! LeaderboardBucket(board, id)  -> Vec<LeaderboardEntry>, ranked
+//! ```
+//!
+//! In storage keys a `Board` is packed into a `u64` (see `Board::key`);
+//! the struct with its field names would not fit the ledger key size limit.
+//!
+//! An insert reads and rewrites the whole index, and reads one bucket and
+//! writes at most two (a full bucket is split in half). Taking an entry off
+//! a board merges its bucket into a neighbour once it falls under half full,
+//! re-splitting if that overfills it. Every bucket but a lone one thus holds
+//! at least `BUCKET_SIZE / 2` entries, and the index lists at most
+//! `max(1, 2 * len / BUCKET_SIZE)` buckets: the buckets touched per call
+//! stay few, but the index grows with the board. Board capacity is what
+//! bounds it; the contract's `LEADERBOARD_MAX` keeps the index under 64
+//! buckets, so the board writes of a `submit_score` or settlement fit in
+//! one transaction.
+//!
+//! Pages are read by rank offset, skipping whole buckets via the index, and
+//! a player's rank is found the same way from their score.
+//!
+//! Ranking follows `GameMode::ranks_ahead`. Ties keep the earlier entry
+//! ahead. The caller passes the board's capacity to `insert`; once a board
+//! holds that many entries, an entry that would rank last is dropped and a
+//! better one evicts the current last entry.
+//!
+//! Each player holds at most one entry per board, their personal best, also
+//! kept under `PlayerBest(board, player)`. A better run replaces it; anything
+//! else leaves the board untouched.
+//!
+//! Board entries are kept alive for `BOARD_TTL` ledgers past the last
+//! transaction that read or wrote them. Queries run as simulations and
+//! extend nothing, so a past season's entries are archived about a month
+//! after its last play and must be restored (`RestoreFootprint`) before
+//! they can be read again.
+//!
+//! Every season has its own boards. With a season length set, seasons
+//! follow each other back to back, so the current season is derived from
+//! the clock: a season started at `t0` with length `L` is followed by one
+//! starting at `t0 + L`, and so on. The contract stores a rolled-over
+//! season, and announces it, on the first session start or settlement after
+//! the rollover (see `roll_over`); seasons that pass without play are not
+//! announced.
+
+use soroban_sdk::{contracttype, Address, Env, TryFromVal, Val, Vec};
+
+use crate::{Board, DataKey, LeaderboardEntry, LeaderboardPage, Season};
+
+const BUCKET_SIZE: u32 = 64;
+const PAGE_MAX:    u32 = 100;   // entries returned by one `page` call at most
+const BOARD_TTL:   u32 = 518_400;
+
+impl Board {
+    /// Storage id: season, mode, difficulty and level packed into one `u64`.
+    pub(crate) fn key(self) -> u64 {
+        (self.season as u64) << 32
+            | (self.mode as u64) << 24
+            | (self.difficulty as u64) << 16
+            | self.level.min(0xffff) as u64
+    }
+}
+
+/// Season in progress.
+pub fn current_season(env: &Env) -> Season {
+    let season: Season = env.storage().instance()
+        .get(&DataKey::Season)
+        .unwrap_or(Season { id: 1, started_at: 0 });
+    let Some(length) = env.storage().instance().get::<_, u64>(&DataKey::SeasonLength) else {
+        return season;
+    };
+    let elapsed = env.ledger().timestamp().saturating_sub(season.started_at) / length;
+    Season {
+        id:         season.id + elapsed as u32,
+        started_at: season.started_at + elapsed * length,
+    }
+}
+
+/// End the current season now and start the next one.
+pub fn start_season(env: &Env) -> Season {
+    let season = Season {
+        id:         current_season(env).id + 1,
+        started_at: env.ledger().timestamp(),
+    };
+    env.storage().instance().set(&DataKey::Season, &season);
+    season
+}
+
+/// Store the season in progress if the clock has moved past the stored
+/// one. Returns the new season after a rollover.
+pub fn roll_over(env: &Env) -> Option<Season> {
+    let season = current_season(env);
+    let stored: Option<Season> = env.storage().instance().get(&DataKey::Season);
+    if stored.as_ref() == Some(&season) {
+        return None;
+    }
+    env.storage().instance().set(&DataKey::Season, &season);
+    Some(season)
+}
+
+/// Set the automatic season length; `None` or 0 turns rollover off. The
+/// current season is kept, and the new length counts from its start.
+pub fn set_season_length(env: &Env, length_secs: Option<u64>) {
+    env.storage().instance().set(&DataKey::Season, &current_season(env));
+    match length_secs {
+        Some(length) if length > 0 => env.storage().instance().set(&DataKey::SeasonLength, &length),
+        _ => env.storage().instance().remove(&DataKey::SeasonLength),
+    }
+}
+
+/// Position of one bucket in a board
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BucketInfo {
+    pub id:   u32,
+    pub len:  u32,
+    pub last: u64,  // score of the bucket's last entry
+}
+
+/// Bucket layout of a board, in rank order
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BoardIndex {
+    pub buckets: Vec<BucketInfo>,
+    pub len:     u32,  // entries over all buckets
+    pub next_id: u32,  // id for the next new bucket
+}
+
+/// Add `entry` to the `board` at its rank, replacing the player's
+/// previous entry. Returns false if the entry does not beat the player's
+/// personal best, or if the board already holds `max_len` entries and the
+/// entry would rank last.
+pub fn insert(env: &Env, board: Board, entry: LeaderboardEntry, max_len: u32) -> bool {
+    let mut index = load_index(env, board);
+    let score = entry.time_ms;
+
+    let best_key = DataKey::PlayerBest(board.key(), entry.player.clone());
+    if let Some(best) = player_best(env, board, entry.player.clone()) {
+        if !board.mode.ranks_ahead(score, best.time_ms) {
+            return false;
+        }
+        remove(env, board, &mut index, &best);
+    }
+
+    if index.len >= max_len {
+        let last = index.buckets.last_unchecked();
+        if !board.mode.ranks_ahead(score, last.last) {
+            return false;
+        }
+        let evicted = evict_last(env, board, &mut index);
+        env.storage().persistent().remove(&DataKey::PlayerBest(board.key(), evicted.player));
+    }
+
+    // First bucket holding an entry the new one ranks ahead of; otherwise
+    // the entry goes to the end of the last bucket.
+    let slot = match index.buckets.iter().position(|b| board.mode.ranks_ahead(score, b.last)) {
+        Some(slot) => slot as u32,
+        None if index.buckets.is_empty() => {
+            let info = BucketInfo { id: index.next_id, len: 0, last: score };
+            index.next_id += 1;
+            index.buckets.push_back(info);
+            0
+        }
+        None => index.buckets.len() - 1,
+    };
+
+    let mut bucket = load_bucket(env, board, index.buckets.get_unchecked(slot).id);
+    let at = bucket.iter()
+        .position(|e| board.mode.ranks_ahead(score, e.time_ms))
+        .map_or(bucket.len(), |at| at as u32);
+    bucket.insert(at, entry.clone());
+    index.len += 1;
+
+    store_bucket(env, board, &mut index, slot, bucket);
+    save_index(env, board, &index);
+
+    env.storage().persistent().set(&best_key, &entry);
+    env.storage().persistent().extend_ttl(&best_key, BOARD_TTL, BOARD_TTL);
+    true
+}
+
+/// `player`'s entry on the `board`, if they have one.
+pub fn player_best(env: &Env, board: Board, player: Address) -> Option<LeaderboardEntry> {
+    load(env, &DataKey::PlayerBest(board.key(), player))
+}
+
+/// Rank offset of `player`'s entry on the `board`, 0 being the top.
+pub fn rank(env: &Env, board: Board, player: Address) -> Option<u32> {
+    let best = player_best(env, board, player)?;
+    let index = load_index(env, board);
+    find(env, board, &index, &best).map(|found| found.rank)
+}
+
+/// `player`'s entry with up to `radius` entries on either side; the radius
+/// is capped so the result fits in one page.
+pub fn around(env: &Env, board: Board, player: Address, radius: u32) -> Vec<LeaderboardEntry> {
+    let Some(rank) = rank(env, board, player) else {
+        return Vec::new(env);
+    };
+    let radius = radius.min((PAGE_MAX - 1) / 2);
+    let start = rank.saturating_sub(radius);
+    page(env, board, start, rank - start + radius + 1).entries
+}
+
+/// Up to `limit` entries (at most `PAGE_MAX`) starting at rank offset
+/// `cursor`, 0 being the top entry.
+pub fn page(env: &Env, board: Board, cursor: u32, limit: u32) -> LeaderboardPage {
+    let index = load_index(env, board);
+    let limit = limit.min(PAGE_MAX);
+    let mut entries = Vec::new(env);
+    let mut skip = cursor;
+
+    for info in index.buckets.iter() {
+        if entries.len() >= limit {
+            break;
+        }
+        if skip >= info.len {
+            skip -= info.len;
+            continue;
+        }
+        let bucket = load_bucket(env, board, info.id);
+        let take = (info.len - skip).min(limit - entries.len());
+        entries.append(&bucket.slice(skip..skip + take));
+        skip = 0;
+    }
+
+    let end = cursor.saturating_add(entries.len());
+    let next_cursor = if end < index.len { Some(end) } else { None };
+    LeaderboardPage { entries, next_cursor }
+}
+
+/// Drop the lowest-ranked entry and return it.
+fn evict_last(env: &Env, board: Board, index: &mut BoardIndex) -> LeaderboardEntry {
+    let slot = index.buckets.len() - 1;
+    let info = index.buckets.get_unchecked(slot);
+    let mut bucket = load_bucket(env, board, info.id);
+    let evicted = bucket.pop_back_unchecked();
+    update_bucket(env, board, index, slot, bucket);
+    evicted
+}
+
+/// Where an entry sits on a board
+struct Found {
+    slot:   u32,  // position of its bucket in the index
+    at:     u32,  // position within the bucket
+    rank:   u32,  // position on the board
+    bucket: Vec<LeaderboardEntry>,
+}
+
+/// Locate `entry` on the board. Entries with the same score may span
+/// several buckets, so the search starts at the first bucket that can hold
+/// that score and reads on until the player is found.
+fn find(env: &Env, board: Board, index: &BoardIndex, entry: &LeaderboardEntry) -> Option<Found> {
+    let mut rank = 0;
+    for (slot, info) in index.buckets.iter().enumerate() {
+        if board.mode.ranks_ahead(info.last, entry.time_ms) {
+            rank += info.len;
+            continue;
+        }
+        let bucket = load_bucket(env, board, info.id);
+        if let Some(at) = bucket.iter().position(|e| e.player == entry.player) {
+            let at = at as u32;
+            return Some(Found { slot: slot as u32, at, rank: rank + at, bucket });
+        }
+        rank += info.len;
+    }
+    None
+}
+
+/// Take `entry` off the board.
+fn remove(env: &Env, board: Board, index: &mut BoardIndex, entry: &LeaderboardEntry) {
+    if let Some(mut found) = find(env, board, index, entry) {
+        found.bucket.remove(found.at);
+        update_bucket(env, board, index, found.slot, found.bucket);
+    }
+}
+
+/// Store a bucket that lost one entry. Under half full, it is merged with
+/// the next bucket (the previous one for the last bucket); a lone bucket is
+/// only dropped once empty.
+fn update_bucket(env: &Env, board: Board, index: &mut BoardIndex, slot: u32, bucket: Vec<LeaderboardEntry>) {
+    index.len -= 1;
+
+    if index.buckets.len() == 1 || bucket.len() >= BUCKET_SIZE / 2 {
+        if bucket.is_empty() {
+            drop_bucket(env, board, index, slot);
+        } else {
+            store_bucket(env, board, index, slot, bucket);
+        }
+        return;
+    }
+
+    let first = if slot + 1 < index.buckets.len() { slot } else { slot - 1 };
+    let second = index.buckets.get_unchecked(first + 1);
+    let merged = if first == slot {
+        let mut merged = bucket;
+        merged.append(&load_bucket(env, board, second.id));
+        merged
+    } else {
+        let mut merged = load_bucket(env, board, index.buckets.get_unchecked(first).id);
+        merged.append(&bucket);
+        merged
+    };
+    drop_bucket(env, board, index, first + 1);
+    store_bucket(env, board, index, first, merged);
+}
+
+/// Save `bucket` under the index entry at `slot`, splitting it in half if it
+/// holds more than `BUCKET_SIZE` entries: the back half moves to a new bucket
+/// right after this one.
+fn store_bucket(env: &Env, board: Board, index: &mut BoardIndex, slot: u32, mut bucket: Vec<LeaderboardEntry>) {
+    let mut info = index.buckets.get_unchecked(slot);
+
+    if bucket.len() > BUCKET_SIZE {
+        let back = bucket.slice(bucket.len() / 2..);
+        bucket = bucket.slice(..bucket.len() / 2);
+        let new_info = BucketInfo {
+            id:   index.next_id,
+            len:  back.len(),
+            last: back.last_unchecked().time_ms,
+        };
+        index.next_id += 1;
+        save_bucket(env, board, new_info.id, &back);
+        index.buckets.insert(slot + 1, new_info);
+    }
+
+    info.len = bucket.len();
+    info.last = bucket.last_unchecked().time_ms;
+    save_bucket(env, board, info.id, &bucket);
+    index.buckets.set(slot, info);
+}
+
+/// Delete the bucket at `slot` and its index entry.
+fn drop_bucket(env: &Env, board: Board, index: &mut BoardIndex, slot: u32) {
+    let info = index.buckets.get_unchecked(slot);
+    env.storage().persistent().remove(&DataKey::LeaderboardBucket(board.key(), info.id));
+    index.buckets.remove(slot);
+}
+
+/// Read a persistent board entry, extending its TTL if it exists.
+fn load<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let value = env.storage().persistent().get(key)?;
+    env.storage().persistent().extend_ttl(key, BOARD_TTL, BOARD_TTL);
+    Some(value)
+}
+
+fn load_index(env: &Env, board: Board) -> BoardIndex {
+    load(env, &DataKey::Leaderboard(board.key()))
+        .unwrap_or_else(|| BoardIndex { buckets: Vec::new(env), len: 0, next_id: 0 })
+}
+
+fn save_index(env: &Env, board: Board, index: &BoardIndex) {
+    let key = DataKey::Leaderboard(board.key());
+    env.storage().persistent().set(&key, index);
+    env.storage().persistent().extend_ttl(&key, BOARD_TTL, BOARD_TTL);
+}
+
+fn load_bucket(env: &Env, board: Board, id: u32) -> Vec<LeaderboardEntry> {
+    load(env, &DataKey::LeaderboardBucket(board.key(), id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn save_bucket(env: &Env, board: Board, id: u32, bucket: &Vec<LeaderboardEntry>) {
+    let key = DataKey::LeaderboardBucket(board.key(), id);
+    env.storage().persistent().set(&key, bucket);
+    env.storage().persistent().extend_ttl(&key, BOARD_TTL, BOARD_TTL);
+}
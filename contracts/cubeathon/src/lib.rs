@@ -1,28 +1,97 @@
 #![no_std]
+// Entrypoint parameters are the contract ABI; grouping them would only move
+// the arguments into an extra struct for every client.
+#![allow(clippy::too_many_arguments)]
 
 //! # Cubeathon — ZK Speed Run on Stellar
 //!
-//! A two-player competitive speed-run game where players navigate a cube
-//! through obstacle walls as fast as possible across 3 levels.
+//! A two-player competitive game where players navigate a cube through
+//! obstacle walls. Each session is played in one `GameMode` (see `rules.rs`):
+//!   - Race: clear 3 levels as fast as possible. Levels unlock in order, and
+//!     each level is played on its own track derived from the session seed.
+//!   - Survival: a single endless level; the longest run wins.
 //!
 //! ## ZK Mechanic
-//! Each level-completion is proven with a ZK commitment:
-//!   - Client generates:  journal_hash = SHA-256(session_id ‖ player ‖ level ‖ time_ms ‖ nonce)
+//! Each run is proven with a ZK commitment (see `commitment.rs`):
+//!   - Contract derives: journal_hash = Poseidon(domain, seed, player_id, time_ms, nonce),
+//!     the same construction the circuit uses for its `outcome_hash`, where
+//!     `seed` is generated by `start_game` and stored in `GameState`, and
+//!     `domain` binds the network, this contract and the circuit version
 //!   - Contract verifies: the verifier contract checks proof + image_id + journal_hash
 //!
-//! This ensures a player CANNOT falsely claim a faster time without a valid
-//! execution trace that respects all obstacle boundaries.
+//! The verifier only has a Groth16 backend so far and no Groth16 build of
+//! the circuit exists, so the UltraHonk proofs `bb` produces for it cannot
+//! be checked on-chain yet; deployments run under `ProofPolicy::Optional`.
+//!
+//! The circuit ties the claimed time to a path that clears every wall
+//! reached in that time, so a proven survival time cannot be inflated; a
+//! proven Race time can still be understated (see `rules.rs`).
+//!
+//! Each accepted journal hash is recorded as a nullifier and cannot be
+//! submitted again. Journals are bound to the session seed, so nullifiers
+//! are kept per session and their TTL is extended whenever the session's is.
+//!
+//! ## Proof Policy
+//!   - Required: `submit_score` rejects runs without a proof.
+//!   - Optional: unproven runs count for the session but are kept off the
+//!     global leaderboard. Race runs always count as unproven there, since
+//!     the circuit does not constrain their time (see `rules.rs`).
+//!   - Dev: unproven runs are accepted everywhere; local testing only.
+//!
+//! Proofs that are supplied are always verified. Set at construction and
+//! changeable by the admin via `set_proof_policy`.
+//!
+//! ## Seeding Modes
+//!   - Contract (default): `start_game` draws the seed from the ledger PRNG.
+//!   - Commit-reveal: each player commits SHA-256(entropy) in `start_game`,
+//!     signing their own commitment, and later calls `reveal_seed`;
+//!     seed = H(entropy₁ ‖ entropy₂ ‖ session).
+//!     Neither player can grind the track, and nobody sees it before both
+//!     reveals are in. If the reveal deadline passes, `claim_reveal_timeout`
+//!     awards the game to the player who revealed, or cancels it if neither did.
 //!
 //! ## Game Hub Integration
 //! Calls `start_game` and `end_game` on the shared Game Hub contract.
-//! Winner = player who cleared all 3 levels fastest.
+//! `end_session` settles once both players have cleared every level, or
+//! once the admin-configured `SessionDeadline` has passed. A player whose
+//! opponent has not played at all by then can `claim_forfeit` instead.
+//! Unfinished sessions can be retired with `cancel_session`, signed by both
+//! players or by the admin. The hub's only way to close a session is
+//! `end_game(player1_won)`, which names a winner, so it is not told about
+//! cancellations and its session stays open.
+//!
+//! ## Leaderboards
+//! Boards are keyed by season, game mode, difficulty and level: level 0
+//! ranks session winners by combined time, and each level N has a board for
+//! the best single run on it. A level run is listed as soon as it is
+//! submitted, by either player and whatever the session's outcome.
+//! Every board holds each player's personal best
+//! (see `leaderboard.rs`). Seasons change when the admin calls
+//! `start_new_season` or, with a season length set, on a fixed schedule.
+//! Past seasons stay readable through `get_season_leaderboard`.
+//! Winner = the player ahead under the session's `GameMode` rules. Equal
+//! standings are settled by the admin-configured `TieBreak`, which can end
+//! the session in a draw or replay it on a fresh track. `end_game` has no
+//! draw result, so what the hub hears about a draw is the admin-configured
+//! `DrawReport`: by default nothing, leaving the hub session open, or a
+//! player 1 win (see `report_to_hub`). The contract's own outcome stays a
+//! draw either way.
+
+use core::cmp::Ordering;
 
 use soroban_sdk::{
-    contract, contractclient, contracterror, contractimpl, contracttype,
-    panic_with_error, symbol_short, vec, Address, Bytes, BytesN, Env,
-    IntoVal, Symbol, Val, Vec, String,
+    contract, contractclient, contracterror, contractevent, contractimpl,
+    contracttype, Address, Bytes, BytesN, Env, IntoVal, Vec,
 };
 
+mod commitment;
+mod leaderboard;
+mod poseidon;
+mod rules;
+
+#[cfg(test)]
+mod test;
+
 // ============================================================================
 // External Contract Interfaces
 // ============================================================================
@@ -44,6 +113,7 @@ pub trait GameHub {
 #[contractclient(name = "VerifierClient")]
 pub trait Verifier {
     fn verify(env: Env, proof: Bytes, image_id: BytesN<32>, journal_hash: BytesN<32>);
+    fn get_circuit_version(env: Env, image_id: BytesN<32>) -> Option<u32>;
 }
 
 // ============================================================================
@@ -61,17 +131,200 @@ pub enum Error {
     NotInitialized    = 5,
     InvalidLevel      = 6,
     LevelNotUnlocked  = 7,
+    InvalidNonce      = 8,
+    SeedNotRevealed   = 9,
+    NotCommitReveal   = 10,
+    AlreadyRevealed   = 11,
+    RevealMismatch    = 12,
+    RevealDeadlinePassed  = 13,
+    RevealDeadlineNotReached = 14,
+    ProofRequired     = 15,
+    JournalReplayed   = 16,
+    SessionInProgress = 17,
+    SessionCancelled  = 18,
+    SessionExpired    = 19,
+    DeadlineNotReached = 20,
+    NoScoreSubmitted  = 21,
+    OpponentHasPlayed = 22,
+    SessionAlreadyExists = 23,
+    UnknownCircuit    = 24,
+    SessionIdReserved = 25,
 }
 
 // ============================================================================
 // Data Types
 // ============================================================================
 
+/// Best run on one level
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LevelResult {
+    pub time_ms:    u64,
+    pub verified:   bool, // time_ms is backed by a verified proof
+    pub ledger:     u32,  // ledger sequence the run was submitted in
+}
+
 /// Per-player progress within a session
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct PlayerProgress {
-    pub max_time_ms:    u64,  // Longest survival time in this session
+    pub levels:     Vec<LevelResult>, // best run per cleared level, index = level - 1
+}
+
+impl PlayerProgress {
+    /// Sum of the best times over all cleared levels.
+    pub fn total_time_ms(&self) -> u64 {
+        self.levels.iter().map(|l| l.time_ms).sum()
+    }
+
+    /// Ledger in which the current standing was reached (0 if nothing cleared).
+    pub fn reached_at(&self) -> u32 {
+        self.levels.iter().map(|l| l.ledger).max().unwrap_or(0)
+    }
+
+    /// True if every cleared level is backed by a verified proof.
+    pub fn verified(&self) -> bool {
+        self.levels.iter().all(|l| l.verified)
+    }
+}
+
+/// Rule set a session is played under, chosen at `start_game`
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GameMode {
+    Race,      // fastest total time over all levels
+    Survival,  // longest single run
+}
+
+/// Obstacle speed and density of a session, agreed at `start_game`. Both
+/// players play at the same difficulty, and each has its own leaderboards.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+/// How `end_session` settles equal standings
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TieBreak {
+    EarliestSubmission, // whoever reached the standing first; draw if in the same ledger
+    Replay,             // clear progress and play again on a fresh track
+    Draw,               // always a draw
+}
+
+/// What the Game Hub is told when a session ends in a draw
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DrawReport {
+    Skip,     // don't call `end_game`; the hub session stays open
+    Player1,  // report a player 1 win
+}
+
+/// Who authorised a `cancel_session`
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CancelledBy {
+    Players,  // both players signed
+    Admin,
+}
+
+/// Result of `end_session`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SessionOutcome {
+    Winner(Address),
+    Draw,
+    Replay,  // tie under `TieBreak::Replay`; the session continues
+}
+
+/// How `submit_score` treats runs submitted without a proof
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ProofPolicy {
+    Required,  // every run must carry a valid proof
+    Optional,  // unproven runs count for the session, never for the leaderboard
+    Dev,       // unproven runs count everywhere; local testing only
+}
+
+/// Lifecycle of a session
+///
+/// ```text
+/// start_game ─┬─────────────────────────────► Pending ──submit_score──► Active
+///             └─► AwaitingReveal ─reveal_seed─┘   │                      │
+///                     │                           └──────end_session─────┴─► Finalized
+///                     └─claim_reveal_timeout─► Finalized (one reveal) | Expired (none)
+/// ```
+/// `end_session` may also return a replayed session to `Pending`, and
+/// `claim_forfeit` finalizes from `Pending` or `Active`. `cancel_session`
+/// moves any unsettled session to `Cancelled`.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GameStatus {
+    Pending,         // ready to play, no run submitted yet
+    Active,          // at least one run submitted
+    AwaitingReveal,  // commit-reveal seed not ready yet
+    Finalized,       // settled with a winner or a draw
+    Cancelled,       // aborted without a result
+    Expired,         // timed out without a result
+}
+
+impl GameStatus {
+    /// Error returned when an entrypoint is not allowed in this status.
+    fn blocked(self) -> Error {
+        match self {
+            GameStatus::Pending | GameStatus::Active => Error::SessionInProgress,
+            GameStatus::AwaitingReveal => Error::SeedNotRevealed,
+            GameStatus::Finalized      => Error::GameAlreadyEnded,
+            GameStatus::Cancelled      => Error::SessionCancelled,
+            GameStatus::Expired        => Error::SessionExpired,
+        }
+    }
+}
+
+/// How long players have to finish before a session can be settled without them
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SessionDeadline {
+    Seconds(u64),  // after `started_at`
+    Ledgers(u32),  // after `started_ledger`
+}
+
+/// Entropy commitments for the commit-reveal seeding mode
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SeedCommitments {
+    pub p1_commit:  BytesN<32>,  // SHA-256(player1 entropy)
+    pub p2_commit:  BytesN<32>,  // SHA-256(player2 entropy)
+}
+
+/// Each player's own commitment, as they sign it in `start_game`.
+fn own_commits(commitments: &Option<SeedCommitments>) -> (Option<BytesN<32>>, Option<BytesN<32>>) {
+    match commitments {
+        Some(c) => (Some(c.p1_commit.clone()), Some(c.p2_commit.clone())),
+        None    => (None, None),
+    }
+}
+
+/// Commit-reveal progress within a session
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SeedReveal {
+    pub commitments: SeedCommitments,
+    pub p1_entropy:  Option<BytesN<32>>,
+    pub p2_entropy:  Option<BytesN<32>>,
+    pub deadline:    u64,  // ledger timestamp after which reveals are refused
+}
+
+/// Where a session's track seed comes from
+#[contracttype]
+#[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)] // host object handles; size only matters off-chain
+pub enum Seeding {
+    Ledger,                   // drawn from the ledger PRNG in `start_game`
+    CommitReveal(SeedReveal), // derived from both players' revealed entropy
 }
 
 /// Full session state
@@ -82,10 +335,47 @@ pub struct GameState {
     pub player2:    Address,
     pub p1_points:  i128,
     pub p2_points:  i128,
+    pub mode:       GameMode,
+    pub difficulty: Difficulty,
     pub p1_progress: PlayerProgress,
     pub p2_progress: PlayerProgress,
     pub winner:     Option<Address>,
     pub started_at: u64,  // ledger timestamp
+    pub started_ledger: u32,  // ledger sequence
+    pub deadline:   SessionDeadline,  // snapshot of the contract setting at start
+    pub seed:       Option<BytesN<32>>, // track seed, a canonical BN254 scalar; None until revealed
+    pub seeding:    Seeding,
+    pub status:     GameStatus,
+    pub draw:       bool,  // finalized as a draw
+    pub forfeit:    bool,  // finalized by `claim_forfeit` against a no-show
+}
+
+impl GameState {
+    /// True once the session has left play for good.
+    pub fn is_over(&self) -> bool {
+        matches!(self.status, GameStatus::Finalized | GameStatus::Cancelled | GameStatus::Expired)
+    }
+
+    /// True once the session deadline has passed.
+    pub fn deadline_passed(&self, env: &Env) -> bool {
+        match self.deadline {
+            SessionDeadline::Seconds(secs) => {
+                env.ledger().timestamp() > self.started_at.saturating_add(secs)
+            }
+            SessionDeadline::Ledgers(ledgers) => {
+                env.ledger().sequence() > self.started_ledger.saturating_add(ledgers)
+            }
+        }
+    }
+
+    /// Fail with the status-specific error unless the session is in one of `allowed`.
+    fn require_status(&self, allowed: &[GameStatus]) -> Result<(), Error> {
+        if allowed.contains(&self.status) {
+            Ok(())
+        } else {
+            Err(self.status.blocked())
+        }
+    }
 }
 
 /// Global leaderboard entry
@@ -98,20 +388,120 @@ pub struct LeaderboardEntry {
     pub timestamp:  u64,
 }
 
+/// Identifies one leaderboard
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Board {
+    pub season:     u32,
+    pub mode:       GameMode,
+    pub difficulty: Difficulty,
+    pub level:      u32,  // 0 = combined time over all levels; 1.. = best single run on that level
+}
+
+/// A leaderboard season. Each season has its own boards; boards of past
+/// seasons stay readable.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Season {
+    pub id:         u32,
+    pub started_at: u64,  // ledger timestamp
+}
+
+/// One page of `get_leaderboard_page`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct LeaderboardPage {
+    pub entries:     Vec<LeaderboardEntry>,
+    pub next_cursor: Option<u32>,  // cursor of the following page; None on the last page
+}
+
 #[contracttype]
 pub enum DataKey {
     Game(u32),
     GameHubAddress,
     VerifierAddress,
     ImageId,
+    CircuitVersion,     // u32, version of the circuit behind ImageId
+    ProofPolicy,
+    TieBreak,
+    SessionDeadline,
+    DrawReport,
+    NextSessionId,
+    Nullifiers(u32),    // Vec<BytesN<32>>, journal hashes consumed by the session, temporary
     Admin,
-    Leaderboard,        // Vec<LeaderboardEntry>
-    LeaderboardCount,   // u32
+    Leaderboard(u64),   // leaderboard::BoardIndex, persistent; keyed by packed `Board`
+    LeaderboardBucket(u64, u32), // Vec<LeaderboardEntry>, ranked, persistent
+    PlayerBest(u64, Address), // LeaderboardEntry, the player's entry on the board
+    Season,             // Season, as last started or normalized
+    SeasonLength,       // u64 seconds; unset = seasons only change via start_new_season
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[contractevent(topics = ["started", "session"], data_format = "single-value")]
+pub struct SessionStarted {
+    pub session_id: u32,
+}
+
+#[contractevent(topics = ["seed", "revealed"], data_format = "vec")]
+pub struct SeedRevealed {
+    pub session_id: u32,
+    pub player:     Address,
+}
+
+#[contractevent(topics = ["seed", "ready"], data_format = "vec")]
+pub struct SeedReady {
+    pub session_id: u32,
+    pub seed:       BytesN<32>,
+}
+
+#[contractevent(topics = ["reveal", "timeout"], data_format = "vec")]
+pub struct RevealTimedOut {
+    pub session_id: u32,
+    pub winner:     Option<Address>,
+}
+
+#[contractevent(topics = ["session", "settled"], data_format = "vec")]
+pub struct SessionSettled {
+    pub session_id: u32,
+    pub outcome:    SessionOutcome,
+}
+
+#[contractevent(topics = ["session", "cancelled"], data_format = "vec")]
+pub struct SessionCancelled {
+    pub session_id: u32,
+    pub by:         CancelledBy,
+}
+
+#[contractevent(topics = ["session", "forfeit"], data_format = "vec")]
+pub struct SessionForfeited {
+    pub session_id: u32,
+    pub winner:     Address,
+}
+
+#[contractevent(topics = ["season", "started"], data_format = "single-value")]
+pub struct SeasonStarted {
+    pub season_id: u32,
+}
+
+#[contractevent(topics = ["score", "update"], data_format = "vec")]
+pub struct ScoreUpdated {
+    pub session_id: u32,
+    pub player:     Address,
+    pub level:      u32,
+    pub time_ms:    u64,
+    pub verified:   bool,  // run was backed by a verified proof
 }
 
 const GAME_TTL_LEDGERS:  u32 = 518_400; // ~30 days
 const INSTANCE_TTL:      u32 = 518_400;
-const LEADERBOARD_MAX:   u32 = 50;
+const LEADERBOARD_TOP:   u32 = 50;  // entries returned by `get_leaderboard`
+const LEADERBOARD_MAX:   u32 = 2_000; // entries kept per board; bounds the index every insert rewrites
+const REVEAL_WINDOW_SECS: u64 = 3_600; // commit-reveal deadline after start_game
+const DEFAULT_DEADLINE_SECS: u64 = 86_400; // session deadline unless configured
+const ALLOCATED_IDS_START: u32 = 1 << 31; // ids from here up belong to create_session
 
 // ============================================================================
 // Contract
@@ -123,25 +513,49 @@ pub struct CubeathonContract;
 #[contractimpl]
 impl CubeathonContract {
 
+    /// The circuit version is read from the verifier's registry, like in
+    /// `set_image_id`. If `image_id` is not registered yet, it stays unset and
+    /// proven runs fail with `UnknownCircuit` until `set_image_id` is called.
     pub fn __constructor(
         env: Env,
         admin: Address,
         game_hub: Address,
         verifier: Address,
         image_id: BytesN<32>,
+        proof_policy: ProofPolicy,
     ) {
+        let circuit_version = VerifierClient::new(&env, &verifier).get_circuit_version(&image_id);
         env.storage().instance().set(&DataKey::Admin,          &admin);
         env.storage().instance().set(&DataKey::GameHubAddress, &game_hub);
         env.storage().instance().set(&DataKey::VerifierAddress,&verifier);
         env.storage().instance().set(&DataKey::ImageId,        &image_id);
-        env.storage().instance().set(&DataKey::LeaderboardCount, &0u32);
-        let empty: Vec<LeaderboardEntry> = Vec::new(&env);
-        env.storage().instance().set(&DataKey::Leaderboard, &empty);
+        if let Some(version) = circuit_version {
+            env.storage().instance().set(&DataKey::CircuitVersion, &version);
+        }
+        env.storage().instance().set(&DataKey::ProofPolicy,    &proof_policy);
+        env.storage().instance().set(&DataKey::Season, &Season {
+            id:         1,
+            started_at: env.ledger().timestamp(),
+        });
     }
 
     // ── start_game ────────────────────────────────────────────────────────────
-    /// Both players must authorize this transaction (multi-sig).
+    /// Both players must authorize this transaction (multi-sig). Each signs
+    /// over `(session_id, opponent, own_points, mode, difficulty, own_commit)`,
+    /// so neither player can be enrolled, have points committed, be moved to
+    /// other rules, or be handed a seed commitment they cannot open, without
+    /// their consent.
     /// Calls Game Hub's start_game and records session state.
+    ///
+    /// With `seed_commitments` the session uses commit-reveal seeding and no
+    /// scores are accepted until both players have called `reveal_seed`.
+    /// `own_commit` is the player's entry in `seed_commitments`, or `None`.
+    /// `difficulty` applies to both players and picks the session's
+    /// leaderboards.
+    ///
+    /// Fails with `SessionAlreadyExists` while `session_id` is still stored,
+    /// finished or not, and with `SessionIdReserved` for ids at or above
+    /// 2^31, which only `create_session` hands out.
     pub fn start_game(
         env: Env,
         session_id:   u32,
@@ -149,18 +563,61 @@ impl CubeathonContract {
         player2:      Address,
         player1_points: i128,
         player2_points: i128,
+        mode:         GameMode,
+        difficulty:   Difficulty,
+        seed_commitments: Option<SeedCommitments>,
+    ) -> Result<(), Error> {
+        if session_id >= ALLOCATED_IDS_START {
+            return Err(Error::SessionIdReserved);
+        }
+        let (p1_commit, p2_commit) = own_commits(&seed_commitments);
+        player1.require_auth_for_args(
+            (session_id, player2.clone(), player1_points, mode, difficulty, p1_commit).into_val(&env),
+        );
+        player2.require_auth_for_args(
+            (session_id, player1.clone(), player2_points, mode, difficulty, p2_commit).into_val(&env),
+        );
+
+        Self::open_session(
+            &env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            mode,
+            difficulty,
+            seed_commitments,
+        )
+    }
+
+    /// Create the session once both players have authorized it.
+    fn open_session(
+        env: &Env,
+        session_id:   u32,
+        player1:      Address,
+        player2:      Address,
+        player1_points: i128,
+        player2_points: i128,
+        mode:         GameMode,
+        difficulty:   Difficulty,
+        seed_commitments: Option<SeedCommitments>,
     ) -> Result<(), Error> {
         if player1 == player2 {
             panic!("Players must be different");
         }
-        // player1.require_auth(); 
-        player2.require_auth(); // Match initialization signed by Player 2 (the joiner/submitter)
+
+        let key = DataKey::Game(session_id);
+        if env.storage().temporary().has(&key) {
+            return Err(Error::SessionAlreadyExists);
+        }
+        Self::sync_season(env);
 
         // Call the shared Game Hub (real testnet: CB4VZAT2U3UC6XFK3N23SKRF2NDCMP3QHJYMCHHFMZO7MRQO6DQ2EMYG)
         let hub_addr: Address = env.storage().instance()
             .get(&DataKey::GameHubAddress)
             .unwrap();
-        let game_hub = GameHubClient::new(&env, &hub_addr);
+        let game_hub = GameHubClient::new(env, &hub_addr);
         game_hub.start_game(
             &env.current_contract_address(),
             &session_id,
@@ -170,8 +627,23 @@ impl CubeathonContract {
             &player2_points,
         );
 
+        let started_at = env.ledger().timestamp();
+        let (seed, seeding, status) = match seed_commitments {
+            None => (
+                Some(commitment::session_seed(env, session_id)),
+                Seeding::Ledger,
+                GameStatus::Pending,
+            ),
+            Some(commitments) => (None, Seeding::CommitReveal(SeedReveal {
+                commitments,
+                p1_entropy: None,
+                p2_entropy: None,
+                deadline:   started_at + REVEAL_WINDOW_SECS,
+            }), GameStatus::AwaitingReveal),
+        };
+
         let empty_progress = PlayerProgress {
-            max_time_ms:   0,
+            levels:        Vec::new(env),
         };
 
         let state = GameState {
@@ -179,49 +651,118 @@ impl CubeathonContract {
             player2:      player2.clone(),
             p1_points:    player1_points,
             p2_points:    player2_points,
+            mode,
+            difficulty,
             p1_progress:  empty_progress.clone(),
             p2_progress:  empty_progress,
             winner:       None,
-            started_at:   env.ledger().timestamp(),
+            started_at,
+            started_ledger: env.ledger().sequence(),
+            deadline:     Self::get_session_deadline(env.clone()),
+            seed,
+            seeding,
+            status,
+            draw:         false,
+            forfeit:      false,
         };
 
-        let key = DataKey::Game(session_id);
         env.storage().temporary().set(&key, &state);
         env.storage().temporary().extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
         env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
 
         // Emit event
-        env.events().publish(
-            (symbol_short!("started"), symbol_short!("session")),
-            session_id,
-        );
+        SessionStarted { session_id }.publish(env);
 
         Ok(())
     }
 
+    // ── create_session ────────────────────────────────────────────────────────
+    /// Same as `start_game`, but the contract allocates the session id and
+    /// returns it. Ids come from a counter in persistent storage over
+    /// `[2^31, u32::MAX]`, a range `start_game` rejects, so two clients can
+    /// never be handed the same id and no id is ever taken behind the
+    /// counter's back. The id is passed to the Game Hub unchanged.
+    ///
+    /// The id is not known when players sign, so each signs over
+    /// `(opponent, own_points, mode, difficulty, own_commit)` instead.
+    pub fn create_session(
+        env: Env,
+        player1:      Address,
+        player2:      Address,
+        player1_points: i128,
+        player2_points: i128,
+        mode:         GameMode,
+        difficulty:   Difficulty,
+        seed_commitments: Option<SeedCommitments>,
+    ) -> Result<u32, Error> {
+        let (p1_commit, p2_commit) = own_commits(&seed_commitments);
+        player1.require_auth_for_args(
+            (player2.clone(), player1_points, mode, difficulty, p1_commit).into_val(&env),
+        );
+        player2.require_auth_for_args(
+            (player1.clone(), player2_points, mode, difficulty, p2_commit).into_val(&env),
+        );
+
+        let session_id = Self::next_session_id(&env);
+        Self::open_session(
+            &env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            mode,
+            difficulty,
+            seed_commitments,
+        )?;
+        Ok(session_id)
+    }
+
+    /// Take the next id from the allocator, starting at 2^31. After
+    /// `u32::MAX` it wraps back to 2^31; sessions that old have long expired.
+    fn next_session_id(env: &Env) -> u32 {
+        let session_id: u32 = env.storage().persistent()
+            .get(&DataKey::NextSessionId)
+            .unwrap_or(ALLOCATED_IDS_START);
+        let next = session_id.checked_add(1).unwrap_or(ALLOCATED_IDS_START);
+        env.storage().persistent().set(&DataKey::NextSessionId, &next);
+        env.storage().persistent().extend_ttl(&DataKey::NextSessionId, INSTANCE_TTL, INSTANCE_TTL);
+        session_id
+    }
+
     // ── submit_score ──────────────────────────────────────────────────────────
-    /// Called after a player finishes an Endless Run.
+    /// Called after a player clears `level` (1-based). Level N+1 unlocks once
+    /// level N is cleared; replaying a cleared level keeps the better time
+    /// under the session's `GameMode`. A run that may be listed (see
+    /// `ranks`) also goes on the level's board for the session's mode and
+    /// difficulty.
     ///
-    /// ZK verification: proofs that the player survived for `time_ms` 
-    /// without a collision based on the session seed.
+    /// ZK verification: proofs that the player cleared the level in `time_ms`
+    /// without a collision on the track derived from the session seed and
+    /// the level. The contract derives the journal itself, so the proof must
+    /// commit to exactly this session, level, player, time and nonce. An
+    /// empty `proof` is only accepted when the proof policy is not `Required`.
     pub fn submit_score(
         env: Env,
         session_id:  u32,
         player:      Address,
-        time_ms:     u64,       // High score for this run
+        level:       u32,       // 1..=mode.level_count()
+        time_ms:     u64,       // Time to clear the level
+        nonce:       BytesN<32>, // client blinding, must be a canonical BN254 scalar
         proof:       Bytes,     // ZK proof bytes
-        journal_hash: BytesN<32>, // commitment to (session, player, time)
     ) -> Result<bool, Error> {
         player.require_auth();
 
+        if !commitment::is_canonical(&nonce) {
+            return Err(Error::InvalidNonce);
+        }
+
         let key = DataKey::Game(session_id);
         let mut state: GameState = env.storage().temporary()
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if state.winner.is_some() {
-            return Err(Error::GameAlreadyEnded);
-        }
+        state.require_status(&[GameStatus::Pending, GameStatus::Active])?;
 
         let is_p1 = player == state.player1;
         let is_p2 = player == state.player2;
@@ -229,8 +770,34 @@ impl CubeathonContract {
             return Err(Error::NotPlayer);
         }
 
+        let seed = state.seed.clone().ok_or(Error::SeedNotRevealed)?;
+
+        if level == 0 || level > state.mode.level_count() {
+            return Err(Error::InvalidLevel);
+        }
+
+        let cleared = if is_p1 { state.p1_progress.levels.len() } else { state.p2_progress.levels.len() };
+        if level > cleared + 1 {
+            return Err(Error::LevelNotUnlocked);
+        }
+
         // ── ZK Verification ──────────────────────────────────────────────────
-        if proof.len() > 0 {
+        let verified = !proof.is_empty();
+        if !verified && Self::get_proof_policy(env.clone()) == ProofPolicy::Required {
+            return Err(Error::ProofRequired);
+        }
+        if verified {
+            let domain = Self::get_journal_domain(env.clone()).ok_or(Error::UnknownCircuit)?;
+            let track = commitment::level_seed(&env, &seed, state.mode, state.difficulty, level);
+            let journal_hash = commitment::outcome_hash(&env, &domain, &track, &player, time_ms, &nonce);
+            let nullifier_key = DataKey::Nullifiers(session_id);
+            let mut nullifiers: Vec<BytesN<32>> = env.storage().temporary()
+                .get(&nullifier_key)
+                .unwrap_or(Vec::new(&env));
+            if nullifiers.contains(&journal_hash) {
+                return Err(Error::JournalReplayed);
+            }
+
             let verifier_addr: Address = env.storage().instance()
                 .get(&DataKey::VerifierAddress)
                 .unwrap();
@@ -239,80 +806,478 @@ impl CubeathonContract {
                 .unwrap();
             let verifier = VerifierClient::new(&env, &verifier_addr);
             verifier.verify(&proof, &image_id, &journal_hash);
+
+            nullifiers.push_back(journal_hash);
+            env.storage().temporary().set(&nullifier_key, &nullifiers);
+        }
+
+        // A listable run goes on its level's board now, win or lose.
+        if Self::ranks(&env, state.mode, verified) {
+            Self::sync_season(&env);
+            let board = Self::current_board(&env, state.mode, state.difficulty, level);
+            Self::add_to_leaderboard(&env, board, player.clone(), time_ms, session_id);
         }
 
         // ── Update Progress ───────────────────────────────────────────────────
         let progress_mut = if is_p1 { &mut state.p1_progress } else { &mut state.p2_progress };
-        
-        // Update high score for this session if better
-        if time_ms > progress_mut.max_time_ms {
-            progress_mut.max_time_ms = time_ms;
+        let result = LevelResult { time_ms, verified, ledger: env.ledger().sequence() };
+
+        if level == cleared + 1 {
+            progress_mut.levels.push_back(result);
+        } else if state.mode.improves(time_ms, progress_mut.levels.get_unchecked(level - 1).time_ms) {
+            // Keep the best time for a level that was already cleared
+            progress_mut.levels.set(level - 1, result);
         }
+        state.status = GameStatus::Active;
 
-        // Emit high-score event
-        env.events().publish(
-            (symbol_short!("score"), symbol_short!("update")),
-            (session_id, player.clone(), time_ms),
-        );
+        // Emit score event
+        ScoreUpdated {
+            session_id,
+            player: player.clone(),
+            level,
+            time_ms,
+            verified,
+        }
+        .publish(&env);
 
         // Persist updated state
         env.storage().temporary().set(&key, &state);
-        env.storage().temporary().extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Self::extend_session(&env, session_id);
 
         Ok(true)
     }
 
     // ── end_game ──────────────────────────────────────────────────────────────
-    /// Finalize session and pay out to the survivor with the highest time.
+    /// Finalize session and pay out to the player ahead under the session's
+    /// `GameMode`. Equal standings, including two empty ones, are settled by
+    /// the configured `TieBreak`.
+    ///
+    /// Allowed once both players have cleared every level, or once the
+    /// session deadline has passed; before that it fails with
+    /// `DeadlineNotReached` so nobody can lock in a result early.
+    ///
+    /// If only one player has submitted a run, the session ends as a forfeit
+    /// under the same rules as `claim_forfeit`.
     pub fn end_session(
         env: Env,
         session_id: u32,
-    ) -> Result<Address, Error> {
+    ) -> Result<SessionOutcome, Error> {
         let key = DataKey::Game(session_id);
         let mut state: GameState = env.storage().temporary()
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if state.winner.is_some() {
-            return Err(Error::GameAlreadyEnded);
+        state.require_status(&[GameStatus::Pending, GameStatus::Active])?;
+
+        let levels = state.mode.level_count();
+        let both_finished = state.p1_progress.levels.len() == levels
+            && state.p2_progress.levels.len() == levels;
+        if !both_finished && !state.deadline_passed(&env) {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        let p1_played = !state.p1_progress.levels.is_empty();
+        let p2_played = !state.p2_progress.levels.is_empty();
+        if p1_played != p2_played {
+            return Self::settle_forfeit(&env, session_id, state, p1_played);
         }
 
-        // Compare high scores to determine winner (FASTEST TIME WINS for Race)
-        let p1_time = state.p1_progress.max_time_ms;
-        let p2_time = state.p2_progress.max_time_ms;
+        let p1 = &state.p1_progress;
+        let p2 = &state.p2_progress;
+        let p1_won = match state.mode.compare(p1, p2) {
+            Ordering::Greater => Some(true),
+            Ordering::Less    => Some(false),
+            Ordering::Equal   => match Self::get_tie_break(env.clone()) {
+                TieBreak::EarliestSubmission => match p1.reached_at().cmp(&p2.reached_at()) {
+                    Ordering::Less    => Some(true),
+                    Ordering::Greater => Some(false),
+                    Ordering::Equal   => None,
+                },
+                TieBreak::Replay => return Self::replay(&env, session_id, state),
+                TieBreak::Draw   => None,
+            },
+        };
+
+        let outcome = match p1_won {
+            Some(true)  => SessionOutcome::Winner(state.player1.clone()),
+            Some(false) => SessionOutcome::Winner(state.player2.clone()),
+            None        => SessionOutcome::Draw,
+        };
+
+        Self::report_to_hub(&env, session_id, &state, &outcome);
+
+        match p1_won {
+            Some(p1_won) => Self::record_winner(&env, session_id, &mut state, p1_won),
+            None         => state.draw = true,
+        }
+        state.status = GameStatus::Finalized;
+
+        SessionSettled { session_id, outcome: outcome.clone() }.publish(&env);
+
+        // Persist final state
+        env.storage().temporary().set(&key, &state);
+
+        Ok(outcome)
+    }
+
+    /// Forfeit win for `claimant` against an opponent who has not submitted
+    /// a single run by the session deadline. The claimant must have a run on
+    /// record, and unless the proof policy is `Dev`, every run they have must
+    /// be proven. Reported to the hub like any other win, and recorded in
+    /// `GameState::forfeit`.
+    pub fn claim_forfeit(
+        env: Env,
+        session_id: u32,
+        claimant: Address,
+    ) -> Result<(), Error> {
+        claimant.require_auth();
+
+        let state: GameState = env.storage().temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
 
-        let winner = if p1_time > 0 && (p2_time == 0 || p1_time <= p2_time) {
-            state.player1.clone()
+        state.require_status(&[GameStatus::Pending, GameStatus::Active])?;
+
+        let (own, opponent) = if claimant == state.player1 {
+            (&state.p1_progress, &state.p2_progress)
+        } else if claimant == state.player2 {
+            (&state.p2_progress, &state.p1_progress)
         } else {
-            state.player2.clone()
+            return Err(Error::NotPlayer);
         };
+        if own.levels.is_empty() {
+            return Err(Error::NoScoreSubmitted);
+        }
+        if !opponent.levels.is_empty() {
+            return Err(Error::OpponentHasPlayed);
+        }
+        if !state.deadline_passed(&env) {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        let p1_won = claimant == state.player1;
+        Self::settle_forfeit(&env, session_id, state, p1_won)?;
+        Ok(())
+    }
+
+    /// Finalize a session as a forfeit win for the only player with runs.
+    /// Unless the proof policy is `Dev`, every run the winner has must be
+    /// proven, otherwise this fails with `ProofRequired`.
+    fn settle_forfeit(
+        env: &Env,
+        session_id: u32,
+        mut state: GameState,
+        p1_won: bool,
+    ) -> Result<SessionOutcome, Error> {
+        let progress = if p1_won { &state.p1_progress } else { &state.p2_progress };
+        if !progress.verified() && Self::get_proof_policy(env.clone()) != ProofPolicy::Dev {
+            return Err(Error::ProofRequired);
+        }
+
+        let winner = if p1_won { state.player1.clone() } else { state.player2.clone() };
+        let outcome = SessionOutcome::Winner(winner.clone());
+        Self::report_to_hub(env, session_id, &state, &outcome);
+
+        Self::record_winner(env, session_id, &mut state, p1_won);
+        state.forfeit = true;
+        state.status = GameStatus::Finalized;
+
+        SessionForfeited { session_id, winner }.publish(env);
+        SessionSettled { session_id, outcome: outcome.clone() }.publish(env);
+
+        env.storage().temporary().set(&DataKey::Game(session_id), &state);
+        Ok(outcome)
+    }
+
+    /// Retire a session before it is finalized. `by` selects who signs:
+    /// both players together, or the admin alone. The hub is never told:
+    /// `end_game` would have to name a winner, and a cancelled session has
+    /// none. The `session`/`cancelled` event records it instead.
+    pub fn cancel_session(
+        env: Env,
+        session_id: u32,
+        by: CancelledBy,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut state: GameState = env.storage().temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        match by {
+            CancelledBy::Players => {
+                state.player1.require_auth();
+                state.player2.require_auth();
+            }
+            CancelledBy::Admin => Self::require_admin(&env),
+        }
 
+        state.require_status(&[
+            GameStatus::Pending,
+            GameStatus::Active,
+            GameStatus::AwaitingReveal,
+        ])?;
+
+        state.status = GameStatus::Cancelled;
+
+        SessionCancelled { session_id, by }.publish(&env);
+
+        env.storage().temporary().set(&key, &state);
+        Ok(())
+    }
+
+    /// Set the winner and, if all levels were cleared and every run is
+    /// listable (see `ranks`), add their score to the combined board. Level
+    /// boards were filled as the runs came in.
+    fn record_winner(env: &Env, session_id: u32, state: &mut GameState, p1_won: bool) {
+        Self::sync_season(env);
+        let winner = if p1_won { state.player1.clone() } else { state.player2.clone() };
         state.winner = Some(winner.clone());
-        let p1_won = winner == state.player1;
 
-        // Call Game Hub end_game
+        let progress = if p1_won { &state.p1_progress } else { &state.p2_progress };
+        let finished = progress.levels.len() == state.mode.level_count();
+        if finished && Self::ranks(env, state.mode, progress.verified()) {
+            let board = Self::current_board(env, state.mode, state.difficulty, 0);
+            let score = state.mode.score(progress);
+            Self::add_to_leaderboard(env, board, winner, score, session_id);
+        }
+    }
+
+    /// True if a run may go on the leaderboards: a proven one under a mode
+    /// whose proofs rank, or any run in dev mode. Race runs only count as
+    /// proven in dev mode (see `rules.rs`).
+    fn ranks(env: &Env, mode: GameMode, verified: bool) -> bool {
+        (verified && mode.ranks_proofs()) || Self::get_proof_policy(env.clone()) == ProofPolicy::Dev
+    }
+
+    /// Restart a tied session: clear both players' progress, move to a fresh
+    /// track and restart the deadline clock, so runs and proofs from the tied
+    /// round no longer count.
+    fn replay(env: &Env, session_id: u32, mut state: GameState) -> Result<SessionOutcome, Error> {
+        let seed = state.seed.clone().ok_or(Error::SeedNotRevealed)?;
+        state.seed = Some(commitment::replay_seed(env, &seed));
+        state.p1_progress = PlayerProgress { levels: Vec::new(env) };
+        state.p2_progress = PlayerProgress { levels: Vec::new(env) };
+        state.status = GameStatus::Pending;
+        state.started_at = env.ledger().timestamp();
+        state.started_ledger = env.ledger().sequence();
+
+        env.storage().temporary().set(&DataKey::Game(session_id), &state);
+        Self::extend_session(env, session_id);
+
+        SessionSettled { session_id, outcome: SessionOutcome::Replay }.publish(env);
+        Ok(SessionOutcome::Replay)
+    }
+
+    /// Extend the session and its nullifiers together, so a consumed journal
+    /// is remembered for as long as the session can accept it.
+    fn extend_session(env: &Env, session_id: u32) {
+        let storage = env.storage().temporary();
+        storage.extend_ttl(&DataKey::Game(session_id), GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        let nullifiers = DataKey::Nullifiers(session_id);
+        if storage.has(&nullifiers) {
+            storage.extend_ttl(&nullifiers, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        }
+    }
+
+    /// Adapter from `SessionOutcome` to the hub's `end_game(player1_won)`.
+    ///
+    /// The hub cannot record a draw, so a draw is reported as the configured
+    /// `DrawReport` says; the contract's own state and `session`/`settled`
+    /// event still say `Draw`. `Replay` is not final and is not reported.
+    fn report_to_hub(env: &Env, session_id: u32, state: &GameState, outcome: &SessionOutcome) {
+        let player1_won = match outcome {
+            SessionOutcome::Winner(w) => *w == state.player1,
+            SessionOutcome::Draw      => match Self::get_draw_report(env.clone()) {
+                DrawReport::Skip    => return,
+                DrawReport::Player1 => true,
+            },
+            SessionOutcome::Replay    => return,
+        };
         let hub_addr: Address = env.storage().instance()
             .get(&DataKey::GameHubAddress)
             .unwrap();
-        let game_hub = GameHubClient::new(&env, &hub_addr);
-        game_hub.end_game(&session_id, &p1_won);
+        let game_hub = GameHubClient::new(env, &hub_addr);
+        game_hub.end_game(&session_id, &player1_won);
+    }
+
+    // ── Commit-reveal seeding ─────────────────────────────────────────────────
+
+    /// Reveal the entropy behind `player`'s seed commitment. Once both players
+    /// have revealed, the session seed is fixed and play can begin.
+    pub fn reveal_seed(
+        env: Env,
+        session_id: u32,
+        player:     Address,
+        entropy:    BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut state: GameState = env.storage().temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        let is_p1 = player == state.player1;
+        if !is_p1 && player != state.player2 {
+            return Err(Error::NotPlayer);
+        }
+
+        let Seeding::CommitReveal(mut reveal) = state.seeding.clone() else {
+            return Err(Error::NotCommitReveal);
+        };
+        state.require_status(&[GameStatus::AwaitingReveal])?;
+        if env.ledger().timestamp() > reveal.deadline {
+            return Err(Error::RevealDeadlinePassed);
+        }
+
+        let (commit, slot) = if is_p1 {
+            (&reveal.commitments.p1_commit, &mut reveal.p1_entropy)
+        } else {
+            (&reveal.commitments.p2_commit, &mut reveal.p2_entropy)
+        };
+        if slot.is_some() {
+            return Err(Error::AlreadyRevealed);
+        }
+        let digest: BytesN<32> = env.crypto().sha256(&entropy.clone().into()).into();
+        if digest != *commit {
+            return Err(Error::RevealMismatch);
+        }
+        *slot = Some(entropy);
 
-        // Add to leaderboard (only if time > 0)
-        let winner_time = if p1_won { p1_time } else { p2_time };
-        if winner_time > 0 {
-            Self::add_to_leaderboard(&env, winner.clone(), winner_time, session_id);
+        SeedRevealed { session_id, player }.publish(&env);
+
+        if let (Some(e1), Some(e2)) = (&reveal.p1_entropy, &reveal.p2_entropy) {
+            let seed = commitment::revealed_seed(&env, session_id, e1, e2);
+            SeedReady { session_id, seed: seed.clone() }.publish(&env);
+            state.seed = Some(seed);
+            state.status = GameStatus::Pending;
         }
+        state.seeding = Seeding::CommitReveal(reveal);
+
+        env.storage().temporary().set(&key, &state);
+        env.storage().temporary().extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Ok(())
+    }
+
+    /// Settle a commit-reveal session whose reveal deadline has passed
+    /// without both reveals. The only player who revealed wins; if neither
+    /// revealed, the session expires and `None` is returned.
+    pub fn claim_reveal_timeout(
+        env: Env,
+        session_id: u32,
+    ) -> Result<Option<Address>, Error> {
+        let key = DataKey::Game(session_id);
+        let mut state: GameState = env.storage().temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        let Seeding::CommitReveal(reveal) = state.seeding.clone() else {
+            return Err(Error::NotCommitReveal);
+        };
+        state.require_status(&[GameStatus::AwaitingReveal])?;
+        if env.ledger().timestamp() <= reveal.deadline {
+            return Err(Error::RevealDeadlineNotReached);
+        }
+
+        let winner = match (reveal.p1_entropy.is_some(), reveal.p2_entropy.is_some()) {
+            (true, false) => Some(state.player1.clone()),
+            (false, true) => Some(state.player2.clone()),
+            _ => None,
+        };
+
+        match &winner {
+            Some(w) => {
+                Self::report_to_hub(&env, session_id, &state, &SessionOutcome::Winner(w.clone()));
+                state.winner = winner.clone();
+                state.status = GameStatus::Finalized;
+            }
+            None => state.status = GameStatus::Expired,
+        }
+
+        RevealTimedOut { session_id, winner: winner.clone() }.publish(&env);
 
-        // Persist winner state
         env.storage().temporary().set(&key, &state);
-        
         Ok(winner)
     }
 
+    // ── Admin ─────────────────────────────────────────────────────────────────
+
+    /// Point submissions at a different circuit in the verifier's registry.
+    /// Lets a new circuit version roll out without redeploying this contract.
+    /// The circuit version is read from the registry and mixed into the
+    /// journal domain, so proofs made for the previous version stop matching.
+    ///
+    /// Fails with `UnknownCircuit` if the verifier has no such `image_id`.
+    pub fn set_image_id(env: Env, image_id: BytesN<32>) -> Result<(), Error> {
+        Self::require_admin(&env);
+        let verifier_addr: Address = env.storage().instance()
+            .get(&DataKey::VerifierAddress)
+            .unwrap();
+        let circuit_version = VerifierClient::new(&env, &verifier_addr)
+            .get_circuit_version(&image_id)
+            .ok_or(Error::UnknownCircuit)?;
+        env.storage().instance().set(&DataKey::ImageId, &image_id);
+        env.storage().instance().set(&DataKey::CircuitVersion, &circuit_version);
+        Ok(())
+    }
+
+    /// Change how runs without a proof are treated. Applies to submissions
+    /// made from now on, including those in sessions already in progress.
+    pub fn set_proof_policy(env: Env, policy: ProofPolicy) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&DataKey::ProofPolicy, &policy);
+    }
+
+    /// Change how `end_session` settles equal standings.
+    pub fn set_tie_break(env: Env, tie_break: TieBreak) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&DataKey::TieBreak, &tie_break);
+    }
+
+    /// Change what the hub is told when a session ends in a draw.
+    pub fn set_draw_report(env: Env, report: DrawReport) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&DataKey::DrawReport, &report);
+    }
+
+    /// Archive the current season's leaderboards and start a new season.
+    /// Returns the new season id.
+    pub fn start_new_season(env: Env) -> u32 {
+        Self::require_admin(&env);
+        let season = leaderboard::start_season(&env);
+        SeasonStarted { season_id: season.id }.publish(&env);
+        season.id
+    }
+
+    /// Roll seasons over every `length_secs` seconds, counted from the
+    /// start of the current season. `None` (or 0) leaves season changes to
+    /// `start_new_season`.
+    pub fn set_season_length(env: Env, length_secs: Option<u64>) {
+        Self::require_admin(&env);
+        Self::sync_season(&env);
+        leaderboard::set_season_length(&env, length_secs);
+    }
+
+    /// Change the deadline for sessions started from now on. Running
+    /// sessions keep the deadline they started with.
+    pub fn set_session_deadline(env: Env, deadline: SessionDeadline) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&DataKey::SessionDeadline, &deadline);
+    }
+
+    fn require_admin(env: &Env) {
+        let admin: Address = env.storage().instance()
+            .get(&DataKey::Admin)
+            .unwrap();
+        admin.require_auth();
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    }
+
     // ── Leaderboard ───────────────────────────────────────────────────────────
 
     fn add_to_leaderboard(
         env: &Env,
+        board: Board,
         player: Address,
         time_ms: u64,
         session_id: u32,
@@ -324,35 +1289,78 @@ impl CubeathonContract {
             timestamp: env.ledger().timestamp(),
         };
 
-        let mut board: Vec<LeaderboardEntry> = env.storage().instance()
-            .get(&DataKey::Leaderboard)
-            .unwrap_or_else(|| Vec::new(env));
-
-        // Insert sorted (FASTEST survival time first for Race - ASCENDING)
-        let mut inserted = false;
-        let mut new_board: Vec<LeaderboardEntry> = Vec::new(env);
-        for e in board.iter() {
-            if !inserted && entry.time_ms <= e.time_ms {
-                new_board.push_back(entry.clone());
-                inserted = true;
-            }
-            if (new_board.len() as u32) < LEADERBOARD_MAX {
-                new_board.push_back(e.clone());
-            }
-        }
-        if !inserted && (new_board.len() as u32) < LEADERBOARD_MAX {
-            new_board.push_back(entry);
+        leaderboard::insert(env, board, entry, LEADERBOARD_MAX);
+    }
+
+    /// Store a season the clock has rolled over to and publish its
+    /// `SeasonStarted`.
+    fn sync_season(env: &Env) {
+        if let Some(season) = leaderboard::roll_over(env) {
+            SeasonStarted { season_id: season.id }.publish(env);
         }
+    }
 
-        env.storage().instance().set(&DataKey::Leaderboard, &new_board);
-        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    fn current_board(env: &Env, mode: GameMode, difficulty: Difficulty, level: u32) -> Board {
+        Board { season: leaderboard::current_season(env).id, mode, difficulty, level }
     }
 
-    /// Public: get the global leaderboard (sorted by fastest time)
-    pub fn get_leaderboard(env: Env) -> Vec<LeaderboardEntry> {
-        env.storage().instance()
-            .get(&DataKey::Leaderboard)
-            .unwrap_or_else(|| Vec::new(&env))
+    /// Public: key of a board in the season in progress, for the `Board`
+    /// queries below. `level` 0 is the combined-time board; 1 up to the
+    /// mode's `level_count` are the best-run boards of each level.
+    pub fn get_current_board(env: Env, mode: GameMode, difficulty: Difficulty, level: u32) -> Board {
+        Self::current_board(&env, mode, difficulty, level)
+    }
+
+    /// Public: get the top of the current combined `mode` leaderboard at
+    /// `Normal` difficulty, best first
+    pub fn get_leaderboard(env: Env, mode: GameMode) -> Vec<LeaderboardEntry> {
+        let board = Self::current_board(&env, mode, Difficulty::Normal, 0);
+        leaderboard::page(&env, board, 0, LEADERBOARD_TOP).entries
+    }
+
+    /// Public: `player`'s personal best on `board`, which is also their one
+    /// entry on it. None if they have no entry.
+    pub fn get_player_best(env: Env, board: Board, player: Address) -> Option<LeaderboardEntry> {
+        leaderboard::player_best(&env, board, player)
+    }
+
+    /// Public: `player`'s position on `board`, 1 being the top. None if
+    /// they have no entry.
+    pub fn get_rank(env: Env, board: Board, player: Address) -> Option<u32> {
+        leaderboard::rank(&env, board, player).map(|rank| rank + 1)
+    }
+
+    /// Public: `player`'s entry on `board` with up to `radius` entries on
+    /// either side, best first. Empty if they have no entry. At most 100
+    /// entries are returned, so `radius` is effectively capped at 49.
+    pub fn get_entries_around(env: Env, board: Board, player: Address, radius: u32) -> Vec<LeaderboardEntry> {
+        leaderboard::around(&env, board, player, radius)
+    }
+
+    /// Public: page through `board`. `cursor` is the rank offset to start
+    /// at (0 for the top); pass the returned `next_cursor` to get the
+    /// following page. At most 100 entries per page.
+    pub fn get_leaderboard_page(env: Env, board: Board, cursor: u32, limit: u32) -> LeaderboardPage {
+        leaderboard::page(&env, board, cursor, limit)
+    }
+
+    /// Public: page through the combined `mode` leaderboard at `Normal`
+    /// difficulty of any season, current or archived. Paging works as in
+    /// `get_leaderboard_page`, which reaches every other board of a season.
+    pub fn get_season_leaderboard(
+        env: Env,
+        season_id: u32,
+        mode: GameMode,
+        cursor: u32,
+        limit: u32,
+    ) -> LeaderboardPage {
+        let board = Board { season: season_id, mode, difficulty: Difficulty::Normal, level: 0 };
+        leaderboard::page(&env, board, cursor, limit)
+    }
+
+    /// Public: the season in progress.
+    pub fn get_season(env: Env) -> Season {
+        leaderboard::current_season(&env)
     }
 
     // ── Queries ───────────────────────────────────────────────────────────────
@@ -360,4 +1368,45 @@ impl CubeathonContract {
     pub fn get_game(env: Env, session_id: u32) -> Option<GameState> {
         env.storage().temporary().get(&DataKey::Game(session_id))
     }
+
+    pub fn get_image_id(env: Env) -> BytesN<32> {
+        env.storage().instance().get(&DataKey::ImageId).unwrap()
+    }
+
+    /// Version of the circuit behind `get_image_id`; `None` while the
+    /// verifier does not know the image.
+    pub fn get_circuit_version(env: Env) -> Option<u32> {
+        env.storage().instance().get(&DataKey::CircuitVersion)
+    }
+
+    /// The `domain` public input proofs for this deployment must carry.
+    pub fn get_journal_domain(env: Env) -> Option<BytesN<32>> {
+        let version = Self::get_circuit_version(env.clone())?;
+        Some(commitment::journal_domain(&env, version))
+    }
+
+    /// Deadline applied to sessions started from now on; 24 hours unless set.
+    pub fn get_session_deadline(env: Env) -> SessionDeadline {
+        env.storage().instance()
+            .get(&DataKey::SessionDeadline)
+            .unwrap_or(SessionDeadline::Seconds(DEFAULT_DEADLINE_SECS))
+    }
+
+    /// Tie-break rule used by `end_session`; `EarliestSubmission` unless set.
+    pub fn get_tie_break(env: Env) -> TieBreak {
+        env.storage().instance()
+            .get(&DataKey::TieBreak)
+            .unwrap_or(TieBreak::EarliestSubmission)
+    }
+
+    /// Hub report used for drawn sessions; `Skip` unless set.
+    pub fn get_draw_report(env: Env) -> DrawReport {
+        env.storage().instance()
+            .get(&DataKey::DrawReport)
+            .unwrap_or(DrawReport::Skip)
+    }
+
+    pub fn get_proof_policy(env: Env) -> ProofPolicy {
+        env.storage().instance().get(&DataKey::ProofPolicy).unwrap()
+    }
 }
@@ -0,0 +1,647 @@
+//! Poseidon `hash_5` over BN254, using the Protocol 25 permutation host function.
+//!
+//! Matches `poseidon::bn254::hash_5` in `zk/cubeathon_circuit` (circomlib
+//! parameters: t = 6, α = 5, 8 full and 60 partial rounds). The state is
+//! `[0, x₀, …, x₄]` and the digest is the first element after permuting.
+
+use soroban_sdk::{symbol_short, vec, Env, Vec, U256};
+
+const T: usize = 6;
+const ALPHA: u32 = 5;
+const ROUNDS_F: u32 = 8;
+const ROUNDS_P: u32 = 60;
+const ROUNDS: usize = (ROUNDS_F + ROUNDS_P) as usize;
+
+pub fn hash_5(env: &Env, inputs: [U256; 5]) -> U256 {
+    let mut state = vec![env, U256::from_u32(env, 0)];
+    for input in inputs {
+        state.push_back(input);
+    }
+
+    let mds = matrix(env, &MDS);
+    let round_constants = matrix(env, &ROUND_CONSTANTS);
+    let output = env.crypto_hazmat().poseidon_permutation(
+        &state,
+        symbol_short!("BN254"),
+        T as u32,
+        ALPHA,
+        ROUNDS_F,
+        ROUNDS_P,
+        &mds,
+        &round_constants,
+    );
+    output.get_unchecked(0)
+}
+
+fn matrix<const R: usize>(env: &Env, rows: &[[[u64; 4]; T]; R]) -> Vec<Vec<U256>> {
+    let mut out = Vec::new(env);
+    for row in rows {
+        let mut values = Vec::new(env);
+        for limbs in row {
+            values.push_back(U256::from_parts(env, limbs[0], limbs[1], limbs[2], limbs[3]));
+        }
+        out.push_back(values);
+    }
+    out
+}
+
+// Constants from the circomlib / Poseidon reference instance `x5_254_6`,
+// as big-endian 64-bit limbs.
+
+const MDS: [[[u64; 4]; T]; T] = [
+    [
+        [0x124666f80561ed59, 0x16f2f070b1bd248c, 0x6d53f44d273d956a, 0x0c87b917692a4d18],
+        [0x11924f02fd19b092, 0x55aaa1cf46ea0518, 0xe3d7bfeef4742160, 0x9491011db0bd0b02],
+        [0x247fa7f022304a19, 0x94ff505456c2201e, 0xf9b717369498d3ff, 0xce446601ed9df845],
+        [0x03fd7b19ef2c861f, 0x22f77ff810f54e27, 0x7bc94eb76c02d79d, 0x986be3dcdf051c3f],
+        [0x18bd41239c3e7157, 0x9a677443ecffbd55, 0x5a81eeeea69352a6, 0x8b67c8563c0c2a06],
+        [0x2d78c3a5d28de9ff, 0x35bf0a257635196e, 0x5730ca7f40493277, 0x078cd75da8b4ebdc],
+    ],
+    [
+        [0x0a514a5c227f4cec, 0x95dfa029e8dd124c, 0x34895aa46bb27c09, 0x11f3780d5015540a],
+        [0x192e16d17d956b25, 0x7b85a652eefdf2ee, 0x09589eac5be80915, 0x775723d2cb1da06d],
+        [0x298ce0c1e3113bb9, 0x35c7058e7772b533, 0xb1aa9db0c0926bdc, 0x8917e5605ca3ac10],
+        [0x094cb4e83621afd2, 0x71e41bc71727f015, 0x8ebd612239ac9d69, 0x8b17fe4be05b7fc8],
+        [0x03d880395be93c27, 0xd649af5fd142e76b, 0x33918cb8841d5a28, 0x173bd5cf7d328791],
+        [0x28eeae6b5866ad68, 0xe443bbaf91680db7, 0xd7e2b3037e38fef6, 0x1b42cbccffceca81],
+    ],
+    [
+        [0x278bb49a7b4e44ae, 0xa46eb0f882cb6928, 0x01a6e60fdd5b5c23, 0xc63cd65ccce4fe0a],
+        [0x063edec1bed831f5, 0x06af8db648d6fdea, 0x145345887e8bdcff, 0x109035a1d9b674d7],
+        [0x1baef1cb5509b526, 0xa42061fb53657f99, 0xb3232500e855192c, 0xbe8c940e068c475f],
+        [0x1324564ac7bdf9e2, 0x2164e9858d7fa8e3, 0x68b165eaea3daf4e, 0xb67ee59c0df2e5d4],
+        [0x005761b8c6aecb1a, 0x8ca4ea4dfc2c8376, 0x064a4a8004ceeda2, 0x10a55240562ddc13],
+        [0x10c9e283159d58cb, 0x4cb2e35fde83a3ba, 0x1fdc28002ed9963d, 0x2a99f186178a148d],
+    ],
+    [
+        [0x0c399e9f67aa4070, 0x7a203feefb0b958b, 0xbdadcec5ca34901d, 0x253d026a2419f6a2],
+        [0x083f0df3f1a0351d, 0x0330ec3ff602ca8c, 0xc353b7f6e762c710, 0x7184cd7b423449f6],
+        [0x1a6764d5943fc4a7, 0x20b4c0a19fdb8c71, 0x1984307287a58b9b, 0x5f9f5d58212cb263],
+        [0x011a63a26feabf87, 0xfa66bde66cc25a92, 0x2c96382d76c6a7ff, 0x48f1537beaed683a],
+        [0x08ca7b64657c3548, 0xf32bef5b63ad2428, 0x8a41c0b251099ad2, 0x7f9434307e3e64d4],
+        [0x01998270471e9361, 0x955446b0cdb8bea9, 0x15ec0675f1cd648d, 0xdcb04303507a4489],
+    ],
+    [
+        [0x1d6b3d5f6ea369c2, 0x6f825d2362933eaa, 0x31ea35ec0a77c1fb, 0xd9e01ca1523e4432],
+        [0x119ef188bb3dd0d3, 0x2306976c19941e86, 0x64be687e7a69692d, 0xa27da215a6f06d40],
+        [0x2d9e0ab5c06893df, 0xdfd03481381ba86b, 0x6e6292df5609d71f, 0x2c64b2d9a79f809e],
+        [0x25f16631bf77060f, 0x7ea34087c025bf13, 0x5784319ef08cda2e, 0x31419ee0a529e658],
+        [0x144c7a11da5a7c5d, 0xabae3f33fbd03cad, 0x86d18bc594c79a49, 0x7ecb9894edb554f1],
+        [0x0f971162627723f3, 0xfeadacb28b0c104c, 0xb8f74de508752fa8, 0xd7c0db2af13de8ee],
+    ],
+    [
+        [0x24be510095436206, 0xdd0abd0b0cbb95c8, 0x83ab304aa52598b1, 0xa69306ec981a688d],
+        [0x211610e2ad4a3774, 0x26fadf7068b0c1a6, 0xc299a164c1c1a603, 0xeaed944870d0b9b9],
+        [0x15a67d981041b1f6, 0xf09f3f9ebefd864e, 0x779d3af08157786a, 0xc077505e50ec79fc],
+        [0x049327fa79d28c12, 0xa2c82406947f77f0, 0x6775b0287468b313, 0x6877701dbe7c9598],
+        [0x230940dcc5232658, 0xff9c29697a3fd416, 0xd170e8c998f1aa85, 0xdea0c42d79f951aa],
+        [0x1b121c049cd1159e, 0x289007e0c9da9995, 0xcc4bab4c26fb888e, 0xc3972a8a2e656964],
+    ],
+];
+
+const ROUND_CONSTANTS: [[[u64; 4]; T]; ROUNDS] = [
+    [
+        [0x1448614598e00f98, 0xe7ae7dea45fbd83b, 0xd968653ef8390cde, 0x2e86b706ad40c651],
+        [0x0ab7b291388e5c9e, 0x43c0dc1f591fb83e, 0xcdb65022e1b70af4, 0x3b8a7b40c1dff7c3],
+        [0x2b7cbb217896f52c, 0x9a8c088e654af21e, 0x84cde754a3cef5b1, 0x5c4d5466612d6adf],
+        [0x2bc6b0ddbe1d701b, 0x6570428bdc1ca1bf, 0x0da59ff3bbbb95fc, 0x2bc71c0c6e67a65c],
+        [0x123a55a31980384f, 0x3d20b2cecbc44ed6, 0x0c38c11f7d20e927, 0x1efab9a905eefd3c],
+        [0x037501cc8c9dc819, 0x309a769f4df098e5, 0x88b01858bc8eb7e2, 0x79e2883be9fb8c53],
+    ],
+    [
+        [0x1c2116e47e03a86b, 0xb11695b0a5f6dab6, 0xb9a460b1eb951ab0, 0x1c259eca3fd47d51],
+        [0x2c18213489032e85, 0xa9c8cb8e9a65839b, 0xfaed13e57bc0fae4, 0x9dbdaebf54f56f93],
+        [0x2ee8fed3d4d2c71a, 0x0429eafd8e5db171, 0x8f29e2227985fdf2, 0xad8703c835b9e031],
+        [0x28c64d8f5ed7aac0, 0x04c92029d9e9bf91, 0xba9436d1cce94b93, 0x16d111c70a0c1714],
+        [0x18a01d9ffb747ae0, 0xde3e83c707f8b24f, 0x682c84f15abf571b, 0x34254a03478665e0],
+        [0x1c21d92bef197e73, 0xb234e4777b60db14, 0xe642a56cee71515d, 0x54e1ac71cde72bd3],
+    ],
+    [
+        [0x0ad404ccbcb1e195, 0x897cb60c80981ebb, 0x9d66a6677dbbedad, 0x8b6455fe62d807b1],
+        [0x0a9b6de833064f93, 0xb6adb99af6c00594, 0x5cb654cb7bd14c8b, 0x97af8b60cc1fb387],
+        [0x13129e3f930aed6d, 0x47690331ff09dc51, 0x60efa58ddce2c3e6, 0x180d45bec3aa3a6f],
+        [0x0d7a614c8991508a, 0xb1ce4895813bb1c8, 0x2f18bf7bfc9e280c, 0xcca18079839387f1],
+        [0x0532f7ec36e30041, 0xb0486986875c913a, 0x49bddf2f5af5febe, 0x8c31f2f4094ffea5],
+        [0x06bbcb8e8e180201, 0x293e712f4950f1b0, 0xbbee808c9d64263c, 0x84d9d8ae155cb892],
+    ],
+    [
+        [0x0f558a4db1a3ac07, 0xf61e2e6bee947f73, 0x586bf40f211ceb4f, 0x687ca5678a9dcb33],
+        [0x2be140a60b5b5f2f, 0x8edd78a818a969b2, 0x0c643e419bcf0b57, 0x7c24a0d0e7acfe98],
+        [0x1c49c4b9a9f09f7b, 0x9ad5f74ebacc7105, 0x12b8e867bace27cb, 0x0dea06e89b96f631],
+        [0x170c1a732721b12c, 0xde7f33e476a39a1a, 0xa77a81c06e2eac50, 0x3847b00d597652db],
+        [0x19c27d0e52f65ca3, 0x4f4e31a068e49331, 0xc6bfc39d9241f9d4, 0xc302041615cf27f1],
+        [0x2f1bdc5254f9220c, 0x1a731fc5276964da, 0xb26b385fa40b6b04, 0xbed9960e2543ba08],
+    ],
+    [
+        [0x05b42d2fbccbf4d3, 0x9d2be9339cabe9d0, 0xdc6d921e855cd911, 0x54b639d28d4a1cf0],
+        [0x1220040715a41ad5, 0x9f4f410e0c05a42c, 0x5fd32ac52fe9d06f, 0x88188d71f61e0935],
+        [0x25f9526526155b83, 0x946609f7bb9507dd, 0x4925ef871dee916d, 0x9144ebb4ece1343c],
+        [0x017bfe4284299ae7, 0x740b6d0e204951e3, 0x14a8a5d045241914, 0x7978a95b34742444],
+        [0x2a5d4764021ca71d, 0x78a9674cb6708f15, 0x88d2ceaf3578c411, 0x1cf8b359eef089cf],
+        [0x17f02dab745fbe3c, 0x081321fe5cef845e, 0x7b8d070b2514d29b, 0x2a7b7d89cc0815da],
+    ],
+    [
+        [0x19da62626db7199b, 0x65f4adcf57fa4a3d, 0xbaa1764a7bd15570, 0x8ee6f378c89ef013],
+        [0x0f88e295fa2ed81b, 0x426c91fa69366a73, 0xedf75f39bf18634c, 0xd266ec4038829e05],
+        [0x1fe31c5548546c79, 0x48fe4ee1bd7412e3, 0x280eff7d20cb09aa, 0x85f49f2766148017],
+        [0x10fdc1613bdbf67f, 0x38bdde561b2f91e4, 0xcc48b59f98d64363, 0x8fdc0afadbfe126e],
+        [0x1f2618c2ebe95745, 0x08b9c52f0000e33e, 0xbfddad1a03fdd6bc, 0xa6ef7f0093127bef],
+        [0x129fe7fc3efac6a8, 0xab23dba6d886f394, 0xda11f5953cf98e28, 0x269a0dba2a745dd3],
+    ],
+    [
+        [0x15afd4cdf1e4f820, 0xc1631d4ab85ca4ba, 0x3bafcfee72beade9, 0xfae60523102448e3],
+        [0x1f2c74ba5c367e37, 0x0d728e71e15b2688, 0x51a7bb8b45528cb7, 0x34956079ac99b012],
+        [0x1130e1872d76f2f9, 0x369cf59b95edf9ce, 0x19f01fa89c9c36b2, 0x6e09def6786dad3c],
+        [0x13523d173f7e6bad, 0xb73b63fc1c9bbdbe, 0xe242c61bc6865649, 0x3327533a5c1b1dca],
+        [0x14da40d0af427a65, 0xf1841b5adc965386, 0x53368f7254cb5667, 0xddadbbad7a574cd4],
+        [0x0091f96400e4297e, 0xa85bb186c17b304e, 0x82638e57fd631ff6, 0x315976e1a5dd8b86],
+    ],
+    [
+        [0x303329bf9031c551, 0x5b9a34d49a64bb6a, 0x0267bc7b54a0deca, 0x5c450277a002cdcb],
+        [0x14ed47e55c1da1c2, 0xf05d3c1a1b2e6c18, 0x509fc8336ecfe9db, 0x737916e283fa821b],
+        [0x1161f10b357775d8, 0x10ad53bcc4a20d5a, 0xdd2b03251c747deb, 0x04ee94c565e58d6b],
+        [0x17a8a50ae72ce707, 0xf22bc070eb992851, 0xca914eb94cc68eaf, 0xbb8a96a714eb8221],
+        [0x1a6c61d795dbaf62, 0xf99250b37ec5df88, 0x645a1c153791db63, 0x12b932dc250e4f62],
+        [0x1f8bd2ab8aa84066, 0x4c4eee198c4684dc, 0x4b05772bb2a0869d, 0xa6722b15f447a133],
+    ],
+    [
+        [0x1ffcb852a4f0027a, 0x9799f131cd74b98c, 0xcfb8cbc06349d8fe, 0xfcc62f10c8fb3e2f],
+        [0x035e742ec52f19b3, 0x6d489c720f467ffa, 0xd77cd53bc2db5ddd, 0xb246b23021f79f18],
+        [0x1dfaaee41bdf94d7, 0x83aa29fc62b7ec7b, 0x55673aa818d305fd, 0x42d175a05f2e3d86],
+        [0x2821378477a02e99, 0x5005a56350885409, 0x45bd333f2d1455f0, 0x38a219b8c4796b3a],
+        [0x1db4a4d0f238a570, 0xb1061c6eec81c02f, 0x31ffdd4a7c19e763, 0x174f238d04897421],
+        [0x14bf7889457b20b7, 0xa1367b34a3a53821, 0x7d693b52426aff40, 0xa4bb72893b1784ca],
+    ],
+    [
+        [0x2ced52c2bf296f87, 0xe57410c3ec9a9483, 0xa796d164f6049127, 0x109ff0d3a9c08465],
+        [0x1ddeac5805a7f4ad, 0xa4d0441ed108e314, 0x9d4ce6584f49ae5b, 0xdfd46d6766eea334],
+        [0x2e36b4e5e9c97b46, 0x2304e8e2b5f9dc88, 0xe1c9f2161ba04067, 0x3f911123f042ae70],
+        [0x0c6840d1cb0666dc, 0x59e89b1865275d8a, 0x164b447c5ed64347, 0xcaee63502c238d5e],
+        [0x137e2e3e89e71d46, 0x1f4c9bc3e8f12183, 0x262a4d1db55c589b, 0x2caeaac01238f58c],
+        [0x250932e7b0adcf2c, 0x84ed4bfb60a36b6b, 0x82e55aa94751157b, 0x1d45794b081c8aad],
+    ],
+    [
+        [0x170a7292f5634c06, 0xdd3bf09ab5c9c4ec, 0xd4b00d5ce2f35f97, 0x2b4555391f16b42d],
+        [0x0d68cbbe77289e78, 0xd5cbf51d70f1b75b, 0xa215df4e7bd0149d, 0x10b2c50f2a4f3b81],
+        [0x0caf74563b90525f, 0x645a6d2036ecd130, 0x6fa1dc680b49d9ce, 0x4ed24c9749973178],
+        [0x20a7d1c0a27fcce7, 0x8ffe372f4c58306b, 0x166f9456ed46cdeb, 0x255e395b7d30d42a],
+        [0x0623f3226b5470b2, 0x789b8a53040e4444, 0x3385e96b9cfa0be4, 0xd35015158a468465],
+        [0x1632308688c25e79, 0x0f57d68a53502412, 0x42a56305347de4a5, 0x009ce46b8cdcb91f],
+    ],
+    [
+        [0x2de4793a6f99cd14, 0xe3f6642211f4d0b7, 0xbcfa361597c544ff, 0xcb5a567e9076f47f],
+        [0x1d4d06d19ea1b09c, 0xad79086d51bde117, 0x25a554fa99559ca2, 0xf09f3bb73d728c66],
+        [0x0480e7479a66a7cd, 0x9ea61c8b28974389, 0x908350abc4aafc18, 0xcd75e33dd130c144],
+        [0x30430b03368ebcaa, 0x91246960490bcf91, 0x7d78681463e2e7d7, 0x44bfb44335dac24d],
+        [0x0b57b37320127d4c, 0x50f269124b0dbdcb, 0x2b1f1352241a5d12, 0x103283e089c0c742],
+        [0x2cf4890650d27240, 0xe195f60a4f698eda, 0x249b8dd614b23376, 0xb50178d2df6d2b8f],
+    ],
+    [
+        [0x1e221c5526898bfd, 0x12de86851a0d9703, 0x751a2f239008ab5f, 0x9b7d3b6911c64184],
+        [0x28e07485ad7d992e, 0xd1a58f329ca12adc, 0xe4ec693ebddbb295, 0x2e54d339f2eebda5],
+        [0x2f44d64f84de16dc, 0x67bd5ead51efb1dc, 0x8381c84520c12854, 0xdd5ef3a079acd4e0],
+        [0x050a76bc32ebd1df, 0xe2be330f304edc7a, 0xce7167ab7ba1516f, 0x4021c62cf0d4fac2],
+        [0x2f58c45e5d659a67, 0xd781367241f6c35d, 0x8cb46361d97b2894, 0x7d29421c270594a9],
+        [0x25e8da9ae0e42e84, 0x0e04b230370e782b, 0xdb67534844325ba3, 0x6fc7e5e160c66a74],
+    ],
+    [
+        [0x2fec734da20fe320, 0x03ea04f127f84472, 0x4f38a368ba10c295, 0x44252be796040f7f],
+        [0x288a6778f3a83988, 0xa8ed1727f15e93b4, 0xcb14f4e3a3bbb91d, 0xd6d1facafffd5eef],
+        [0x20dcc6c75fd89259, 0xbe7f406750b3db67, 0x9a25a8cd2715d245, 0xb9175390ac922c84],
+        [0x17f42ba10942df25, 0xcb8a541782a18b6f, 0xd31cf965d11178c7, 0xb04ac45b4dea5dd3],
+        [0x028eeb85d115a904, 0x020e0c6148eec660, 0x3e9cedabc664abee, 0x764aafd455986ba5],
+        [0x0b1d7cecf3a79b2a, 0xd3fa298f6cea7ae9, 0x5d80c0299ecc918e, 0x9f8c9c3d38d59d40],
+    ],
+    [
+        [0x0440339c9764cec7, 0x9c16efdb834a2626, 0x1db8e3f12ce1cf72, 0x2d23c0e11ff4cf07],
+        [0x06ca647c29727c19, 0x62a002177da2d504, 0xf4b07a5f7eb57c79, 0xb88e6b7abbbdad5c],
+        [0x2ea120a864f5c409, 0x3dd1a962e8f013c7, 0xb8ef778b04d2ba5b, 0xfc3cab28619ba9e3],
+        [0x2bb737546c4aee7c, 0x0cc2ba87c1157e2a, 0x77c479ebfb5dc76a, 0xdbb39cf8697633fd],
+        [0x0e30da6490625d33, 0xe79cd50176f568f9, 0xa2c28c2f449a2bd5, 0x1a25d15686803a93],
+        [0x0df7ca7278a13650, 0xb919d85497b2ebb0, 0xf71035a7c20430d4, 0x131d903ab7f57521],
+    ],
+    [
+        [0x27cc589f5bf58579, 0x4abace589fb8a74a, 0x2f784c0990b80fca, 0xa6944097f870e2d5],
+        [0x2255c36a38c8735d, 0xe45cedf452afa842, 0x332d33042f78e60c, 0x43c7455421b325bf],
+        [0x133d9602bd3378d6, 0x9f681c27b05bdffc, 0x98b7d86cca63d73a, 0x60caed485784d087],
+        [0x0e1548e942ae9d3e, 0x26860699b93727c8, 0x17a9948616c93ef4, 0xaccd981b1dc3d78a],
+        [0x0f20f0e55d93689f, 0xe09ec312f6af4762, 0x7482e4bde0a1602a, 0x8e2c8d6e84e8a6ae],
+        [0x2e52328483cb5b7f, 0xf2eb4e45b12e51b2, 0x6232c9bc17b72929, 0x54c0a9f6bfa51bb9],
+    ],
+    [
+        [0x02b2162d533e059a, 0x6eda2abb74712edb, 0x3a7860beea95dd8a, 0x4abfc957660804f4],
+        [0x19e0927715d1cc6d, 0x389429947fb3737d, 0xad733974c6b2e13e, 0x5b3d432519516c74],
+        [0x0d3a800457d77785, 0x636303b8b94f17dc, 0xffcb46048872ac9f, 0x74ef7f27ee573705],
+        [0x2c974d1952557a1a, 0xac5f7bae4996616d, 0xa619b73f441c4e50, 0x4dc8fe9cfb559e32],
+        [0x0766bfeeede2ccf3, 0x708e1b4ff30714c2, 0x2c1d434cdbe8f555, 0x14babc2dd5d97bef],
+        [0x23dac8ea54082fc1, 0x31e173ae55e4630c, 0xd4ca7c871b2a0a47, 0x9c1e74e7f191e62c],
+    ],
+    [
+        [0x17d5fb6c2cb37010, 0xe3e358ab2d575376, 0x870ed33186b8eae4, 0x9ad3b47e340a8d7f],
+        [0x175dcac76d8a8126, 0x139b583ae3885329, 0x0246e43e783fa690, 0x3ec8007f178c0023],
+        [0x0c4fd08fede5d221, 0xadb7abf549898c91, 0xe5be7e85bf1fd2a6, 0x11bf182cc2e71655],
+        [0x277934b909e72d3a, 0x3475bb1ec766ab7a, 0x38ad59b128303fc5, 0x002f02a65bdfe729],
+        [0x0e88349998dfe703, 0xf1b184524f9c394d, 0x6004ccacf9cb9528, 0x96e8cfdb0b078b68],
+        [0x1f1b2078b60b0fce, 0x07824e2a2bc8cae8, 0xee673514b0070a8b, 0x45710cc78cbb9942],
+    ],
+    [
+        [0x2eb1559566c536dd, 0xbc316f6482d51fa3, 0x40557657700f5b8a, 0x846e812a0ed334d1],
+        [0x1c4dbdc335cf6764, 0x355208b4c9d243d3, 0x4541d623c669dec2, 0xc3ba066bbeaf6773],
+        [0x2374a6b2da6f8cab, 0x8e5cfe8d805dd3a2, 0xdfca1e8b7eba5dc8, 0x574021fd1241e3b4],
+        [0x19dd342533ccc603, 0xa99738e3fb5a569b, 0x94ef71b3e49f90fb, 0x874f6161733072f4],
+        [0x217d66db6c7fb3ef, 0xfa508800587d2eb3, 0xc6d03d8385132f2f, 0xcce7f35f2705cccf],
+        [0x0815fb8591fe0103, 0x8cd3a3b38b236f9e, 0xfca77c618d3bfc6c, 0x2a7fa89296c7e64f],
+    ],
+    [
+        [0x2bb943b40c2bd456, 0xa6c17853b1ca88eb, 0x0ff36f5974b2ff9a, 0x5f5093e9bf63a16f],
+        [0x11a5153fce659513, 0xee7cb9974ae6cba5, 0x81e3b4cd14570c57, 0x09fec3d8d3fc82e9],
+        [0x1b72bfd07635d850, 0x1b2eff8785a2495b, 0xae74c7653cf90e6d, 0x5c9f144426836df4],
+        [0x14902c0700eec897, 0xae178ba8caf850d7, 0x93f1d87512bea0ec, 0xea39cf6b1fee233d],
+        [0x09c138c6e0a616a4, 0x9ff90d43a6b043f3, 0xb745b78865856dc4, 0xc1a45e2fd84cb3f4],
+        [0x05b58a3dce57b281, 0xa271d69895052d88, 0x7458a715783e8317, 0xe024a61a35ec10bc],
+    ],
+    [
+        [0x2be8d29525c0cfdd, 0x5e6b3125e3bde3bf, 0x558e55fbe867f024, 0x457a96765474d037],
+        [0x061d72f78f1ba9dc, 0x6b4d7f7784225d6a, 0x81bdfc1b5ad6c243, 0x69f9c0560523d9ad],
+        [0x0bf18aefcacffabd, 0xf4112eddadca6145, 0x738b4803b36145bb, 0x9516db501a0692e9],
+        [0x2e73dd105fa8b2ec, 0x931d8cdf29ec679e, 0x3a9801a93071a7d5, 0xea30659255f03bc6],
+        [0x0f8440ef667c9ae8, 0x1337ba5d8c927a53, 0x47de7296860b211c, 0xad1ecbfb5d3598ef],
+        [0x004d303b2dea627b, 0x2731be83f93ac34e, 0x7d14d178a1380055, 0x8ca7396395eb118f],
+    ],
+    [
+        [0x234541ad72040a70, 0xda2996a35269230c, 0x94699eef313a4d48, 0x0508008cbc3d37c1],
+        [0x0d123f1e72d26b92, 0xbdd8fd73d14286c3, 0x12ad4c23acb46b2e, 0x08c157104409e174],
+        [0x2fb360776f0de79d, 0x7098ee7aa4123c05, 0xee6b05a8be460a77, 0x4f3a048e138545bb],
+        [0x03685c079434e167, 0x276c57d3cc79703b, 0x7dfdc41c156ea1e8, 0xb7f99b6956a55326],
+        [0x260af0e0fffcc977, 0x2c1631b179344566, 0xb47aaada3681eb90, 0x34c6f75c3705c1c7],
+        [0x2862b41374f89b69, 0x5274b33b773f2554, 0x916e2bff9ff67254, 0x5fc2f49563f62767],
+    ],
+    [
+        [0x02a9912fe1703102, 0x27189ea1e691d036, 0x2f18b38b400b0eff, 0x192ca59513eba8d5],
+        [0x08e513ade694a0d8, 0xac1f3ebf1a96440d, 0x32c713d5058e1224, 0xe070348c281f4a6f],
+        [0x140a4a431e2ee794, 0x00ed7465978d8477, 0x3213c628264ff80f, 0x21ac7a6b673d09ab],
+        [0x296af4d019cb5df7, 0xd959b29d549c3f07, 0x1202b4eba8b53dc5, 0xee979ed143377927],
+        [0x01832e284a7f4c81, 0x614882b6939fc0f1, 0x85573bd2023e3e50, 0x5765470bb812b349],
+        [0x1a84d56a67bfdd3d, 0x965abdcd329aa78d, 0x4fe93434496f2d10, 0x3861fd19d66d7260],
+    ],
+    [
+        [0x040cb82847773927, 0xd2aefdc07489037a, 0x9d1f7631eca75c9f, 0xb0dda0cb9dbde143],
+        [0x010dcf084cc29cb7, 0xcaecf26aa633bce4, 0xed2b019f2887cee7, 0xb1a78f89d3fabe2f],
+        [0x07edc22a0911ea21, 0x4425ef542b776db2, 0x3b0fe5817810d40c, 0x72ca98aabd9afa83],
+        [0x2eea4ab08aec775f, 0x2148479ea36fbb96, 0x936da58ba48bd1d2, 0xd3acd48173aaabe7],
+        [0x1e40c0e8257fe4a6, 0x1005cdcfad148cf7, 0xf47d1b5cfddfaa08, 0x2738695518245f19],
+        [0x23a27809583bd1ea, 0x51f436de5443e108, 0xf69d44cdf51dc1f0, 0x3e21948b4980b876],
+    ],
+    [
+        [0x2e4652b044dbfe40, 0xe63b6b232fcd5f3f, 0x39abfbd2051ee68a, 0xdc754080d49250a9],
+        [0x11e7abdb6ecbafc2, 0xe7d8cdefe9c7b9c5, 0x0475eb475db3c2ca, 0xf7f7d67f485775f2],
+        [0x199d52350cc30e8c, 0x73821f802096f0e5, 0x47a13551b27bf6b8, 0x99396f63ac5cf8e7],
+        [0x0f575d6ee67cbecd, 0x98345624e032a37c, 0x859a7cbef30b3fdd, 0xc949cd0978484101],
+        [0x1c4b6f9a2ae2b418, 0xe6265acba9c96b06, 0x184d07028e5fb784, 0xf3475ae7772ff057],
+        [0x2dcb5cf8896de39f, 0x228e157c0c5593f4, 0x626fb9bc22520638, 0x3db20360abf0c925],
+    ],
+    [
+        [0x1340abb9f4e11318, 0x6bdc26cbdf4bcca5, 0x0b531a107f863ca5, 0x44575e3cf870f8e1],
+        [0x2368e692b72787cb, 0x8870ea888e714e00, 0x6f59d2b4460cfb74, 0xc48a8cc73b1d1a5b],
+        [0x1fab9add9baa4a4f, 0x56f23165775c6f2d, 0x922a7632a94f9637, 0x4b7dc852756f54b6],
+        [0x0c7f7b82300d3c6c, 0xe3f8957ba1e4add5, 0x4c4c015e20d9765d, 0x220571c16ab8680f],
+        [0x15d63e86beacd93c, 0x6083688e5d9c8f3c, 0x6947929f9f1f99ab, 0x578a4c3a922eff03],
+        [0x0be843ae5f9b07e5, 0x2572178af7dae8ed, 0x05d36b12c0607862, 0x929355ea74023d9e],
+    ],
+    [
+        [0x1332749c523694cb, 0x6935e0963a07e81b, 0x05967ce1d950c0b7, 0x31058ec92a7a0c9a],
+        [0x25439408810e074c, 0x0bdd4598b9815fee, 0x892bb95ca51029ec, 0xf009bffa5b9b9682],
+        [0x057e8d19dd999a91, 0x8da29b0940b383ba, 0x9fd15db0b0f64996, 0xdff67feb55f9a742],
+        [0x1e014e37e9b117cf, 0x3b4870d999f2b55d, 0x3534d0a6be98e9e3, 0x57fa43f01e70a29d],
+        [0x1a4ed24e6e03aebc, 0xd6bdb100533dc966, 0x597afe15c851b4b8, 0x63f6e889084c6479],
+        [0x253420007083f1aa, 0x863ad4760905c103, 0x9ed4111c9f053f27, 0x710452f83ce36a90],
+    ],
+    [
+        [0x2276a1441971709a, 0xffe6d2a99320001e, 0xc45ec72155c575dd, 0xeecac0e32759ab06],
+        [0x28957dd1218ea799, 0xfd3411eb19325853, 0xadf7ae8ae1281f75, 0x3302fe7d31dfa7b0],
+        [0x2fd925726ab794c8, 0x8bd75796aa3e7f1e, 0x6692f2914cf80226, 0x7ddf01e37902a008],
+        [0x1cf8a5c9c76a84b1, 0x47c8238d9253cd55, 0xb47c0c43d82966c4, 0x636a28674705fd9a],
+        [0x0373cbbc306e1bab, 0x9e7077368715e623, 0x0b4b2e2e4a1db9c6, 0x74b8c359a41e9108],
+        [0x060283d2fe7f23df, 0xf513d9110b3dc624, 0x48bc48f531ce0c1e, 0xab5920bf23290a40],
+    ],
+    [
+        [0x0dab465d6d910740, 0xf33ef6cc0eadc71b, 0xf8119bdfd5a3527d, 0xc8bbfadfaa40263c],
+        [0x0cba7bcbc8224b2a, 0x8e4aba17977230a6, 0x86cd6421dc0ca534, 0x6f3446b62439c4c3],
+        [0x1e4365db0790c9c4, 0xf445b0653c466ff2, 0x1db96c38b4076ba8, 0xbd68bcb4dea6911d],
+        [0x1bb2dba2199a9ab3, 0xbc86ef5f9de7f6c5, 0xca13d60eab42ced6, 0x8de98fc643800a8d],
+        [0x0ad3c1870c6d6ef4, 0x0eebad52123cd1a2, 0x913d9d62e80bfbac, 0xae812e082021f9ca],
+        [0x01b098c91e7b0cbb, 0x5c34588077c0ddf9, 0x5300ddf614935630, 0xc0ce3a2627245308],
+    ],
+    [
+        [0x19fd5c0eac14fae7, 0x598bd4ceea3b1e29, 0x98b0c168493b6d72, 0xae41b576e55b9c3f],
+        [0x0d4749d79cc163f1, 0x7110a404a46fe427, 0xc6434f3fe67b7e7b, 0x4ccfa6ab95bd7e18],
+        [0x1ebbfe8114a41bb8, 0x09e0b33399241232, 0xeb940ad8728c8a51, 0x6d40ada440dbfdcf],
+        [0x2704e5b6133d9764, 0xd6d3f17d49d83322, 0x3e3937f80eb9faea, 0xbbfba9baf4b4c1b8],
+        [0x2165e1c8027305b1, 0xae0e323571635e5d, 0x540d13d710c3f9a3, 0x90b6913f14d035e3],
+        [0x2e3497e4d35fda59, 0x6c06afa63bc3a0f2, 0xe55d4eeba4aceb60, 0xe65081ad63aa8b8a],
+    ],
+    [
+        [0x031da4345eecd6db, 0x6c0f7b07c7815d7a, 0xdd1fe0546d738f4d, 0x79ab5c57aa841edf],
+        [0x089ece54e47aa5c9, 0x08e43e5f08737c14, 0x36967089006acab1, 0xc9cd19eac4a20876],
+        [0x2f53c15e2aded33c, 0x47f55a070483e6cc, 0x7f3821fbf8aa4067, 0x7d0552ed9d10d847],
+        [0x142aa34f4b2e8ad0, 0xdf7a21b3e39c00c8, 0xb0aa2857094801ea, 0xafd72befed077f93],
+        [0x17aea4da4c7bcf0d, 0x7588b014eb8b4097, 0x9dd2725eda4e6ace, 0x331982467c7ff2bf],
+        [0x0e970c19d19748d8, 0xc465104d8f022003, 0x63f9a41786f02f18, 0x27742b20dc0d1727],
+    ],
+    [
+        [0x04bcad9e55379564, 0x2f59baf714a6bdb4, 0x32fc45a0a0b77f1a, 0xba3a9823476df9b9],
+        [0x242c0bfbcdaa76f7, 0x15dbd4ba825c71fc, 0xfed671c1b1901fa4, 0x84c87f810315d0ce],
+        [0x25db1343c2410407, 0x1023fb6ed34d9909, 0x078311e1efe85af0, 0xa11b19114fa9e790],
+        [0x2ffe4d9c420a59e9, 0xcdc7c31ab2bf3518, 0x7ca147cb898a3942, 0xdeb3677786036a80],
+        [0x125bb03af3e2cf18, 0xbbe6f5b590eb3bf8, 0xd0d1ba63be696483, 0xe98f283bc7cd07a3],
+        [0x0816be42745b7dbb, 0x4ceffe5b8e24ea60, 0xfd8b719deba50037, 0xac7b75948745c6bc],
+    ],
+    [
+        [0x111160f9acf6ec36, 0x0d1b6a712313a0db, 0xcbe23e6442005547, 0x1d2ee4c5dedb35d4],
+        [0x1377978e1b1f6a89, 0x25fa8e7b7941bdf8, 0xfb59ab9542342419, 0x283d8203435c9391],
+        [0x0defc1d8882166ef, 0x3ccde53a4f236fba, 0x83d384621937cee5, 0x7e421a513d0d3397],
+        [0x2f8fa5c78c706e3a, 0x5d4a03f2a7a39530, 0x46d7e94cb88a7ef3, 0x50e67b5ba0f0debf],
+        [0x1a2a957ec0a723da, 0x61c2134bab0bf17b, 0xeb00e6dcd84690c2, 0x30dcb9e58da94827],
+        [0x1cdf8710995f5e03, 0x412b4a7f699532f9, 0xfd01f0ea167a8dfc, 0x1ddf37e2805addef],
+    ],
+    [
+        [0x26fd31471828c36a, 0xe36c27b748054b0c, 0x0c4fe5239b301699, 0xe3765eebecc18946],
+        [0x0775d996cc2c4456, 0xf303a2c1f9007647, 0xe11a921d9fea3f7b, 0x926143b99d2fa0be],
+        [0x016fb9337708ca63, 0x8cdfda91bd0daea6, 0xb97224ef7b206267, 0x2addd1bd18bb8900],
+        [0x2c392fbe7d3fde42, 0xfca4f9478bb43933, 0x1258255356f184af, 0x6f76f119054117d7],
+        [0x187a2a3bf79a69fa, 0x3e5089ef9f1fd56f, 0xdb47c55eece77aa2, 0x28aa3de1b486bcb1],
+        [0x0271a863a280a326, 0x41ffa33510b2edd2, 0x78c98630359532f3, 0xe506b275fd5d20ce],
+    ],
+    [
+        [0x1557459c9c74c94a, 0xa00e5af69a1e3112, 0xfb69537ce897ec0c, 0x718958d96516f2ab],
+        [0x2a8e26ca8d647d9a, 0x6388516ea9dcff89, 0x083d539e58168c2a, 0x50c6dae30f109f21],
+        [0x21cb752194cf43f3, 0xb51942eb0040eba9, 0xde2bcfb1c2a3fae9, 0x7924b710f26832cd],
+        [0x2c26daf996be247a, 0xcd6dd4acad60d38b, 0x5a471e6322188d02, 0xc137e7cb484377ec],
+        [0x0240176ee0e7982e, 0xebe92a68d3e3a38c, 0x26821acc0f5d058c, 0xf8c137bca2d26f1b],
+        [0x2636e0973c865c1b, 0xd974dd78daaa8d0a, 0x84cdaf6be1ad47ec, 0xf2a0d18f117318f2],
+    ],
+    [
+        [0x19e84f4f25a79949, 0x6041661dc5d975b6, 0x81f6e06744cee89b, 0x7be5d9fde1744ac0],
+        [0x0ebf89064a3af247, 0xca1f36f6f3570188, 0xe271e0b326c4fb26, 0x664e89e1454ca110],
+        [0x25c7e97b475be00e, 0x8b559a38c452364f, 0x4c9c531fecb8ac69, 0x8f7fd73ce22e71ec],
+        [0x0444c99e592353e5, 0xaecaa302add901c1, 0x4d8c55270a160afe, 0xd4429ef5598ad74f],
+        [0x138db8887830565f, 0x2693d0e0f02e4e79, 0xe144967f0ba53b03, 0x519aba764b5c994a],
+        [0x24d40f462114fe9e, 0xe02aafcf74b4fca2, 0x4e1ae365dc75c3b5, 0x2bb13cbbb2f21edd],
+    ],
+    [
+        [0x21e65d6d8ee43760, 0xbca40e730b5df4c4, 0xcf3a8a732db148f4, 0xb2951b4c61d68e8c],
+        [0x248dd79669ec09db, 0xf0350a15d6c75c6a, 0x9bdaacefca14d513, 0x00978f13d1ab6d1c],
+        [0x2b8238c1548f9cbe, 0x29fd35cf91e7b48f, 0x0ebda7e639edf69f, 0xe8d5aba7924d5362],
+        [0x2439fd239257f381, 0x81c7be3cf513f1bf, 0x7235eba94f6b8942, 0xa94cbddecf6f62f7],
+        [0x200958235281a61b, 0xa2c4be0aa3282a18, 0xc74b6d262f5de7c2, 0xe33d2bb3e893dfec],
+        [0x0e1eca5df88ee5f6, 0x0cfa7e1fe5befbb7, 0x19fad8211fa9b2d0, 0x2fcc233190c17f12],
+    ],
+    [
+        [0x26b53427f9b3ea2c, 0x769d9c660fc60881, 0xa169c12732d001b7, 0x158ee4b1b842ca24],
+        [0x20f3b3f4acafe9f8, 0xaf3e06661b3a8f77, 0x8fa2812522b9d70a, 0x67402cff8db2b1b4],
+        [0x211e5d2b39d62520, 0xa7a627ece8cacbac, 0x9f97506def4ec286, 0x928ba6c27d463b17],
+        [0x0bb743ee34802129, 0xc556731aed9d302d, 0xcd085313ce572f62, 0x42d13832e536b4b4],
+        [0x23cb2661b488ee71, 0xe4c753ff23ae4bd2, 0x5d8a44094f66b653, 0x2977e22140eba5cb],
+        [0x03a35aa3123911cd, 0xb4535baed3359f5f, 0x6a5205b9c93ef31d, 0x35323a47807b8bc9],
+    ],
+    [
+        [0x27803848a0aed96a, 0x93fa943b6635e450, 0x217e137f4ade74a6, 0x2d7917322714b697],
+        [0x0cb37839c2c9a7ff, 0x79884cbec75f41e9, 0xbe5e47c76d615382, 0x31bd8162996d6f67],
+        [0x1f0026d0bf1f8e1d, 0xd5423cc2fec1fb5c, 0xdaa1ecdc4c3cb218, 0xdbceef77c00d2f93],
+        [0x02a7d7bb970b8a6e, 0xd2ee66fabbba956b, 0x6da3b100f5b5fb92, 0x8eef42f9708273c9],
+        [0x0cfd7f4215e434c8, 0xda17ec3258b0bc60, 0x5ad1ab2e90aa4943, 0x51e4ee40bbc491fa],
+        [0x180b11b720622a15, 0x6849dc6f7f6e7f57, 0x1659be69682230c5, 0xed9ac339700a7cde],
+    ],
+    [
+        [0x04e96a965bce3d3a, 0x0a24a4a457c95158, 0x2c871349ce7eee1a, 0xabfe578a94c65011],
+        [0x15931f782b45f7fb, 0x656f2cdbd1f7705c, 0x353a23fe1d30a5a4, 0x6a1522ed160df3ad],
+        [0x2e296e57c97a5309, 0xacd26febf55ac963, 0xa5444c1c5f703ad8, 0x8a0d7b97b9dd38b1],
+        [0x26157bceb78e846b, 0xbb262f9a1e06d427, 0x1bde5a5bce8f0419, 0x952f97ffd13eaca8],
+        [0x2194eb89847d6b0f, 0x1897f675f19c0c56, 0xb61b13248eff3ca3, 0x6e34fb9d1c79ee43],
+        [0x2350bf3547765689, 0x9151ad7dde96ea78, 0x57e15501447008da, 0xb6b3d27c8ffa274f],
+    ],
+    [
+        [0x1a486f0ae591cacd, 0xaf09c58a49c4d179, 0x5405435340819e00, 0x3f0469d110b7752b],
+        [0x1b56dcf76fb23cc4, 0xa834d455a4065e13, 0x3571402b7df309d5, 0x9bc3105d42a8c301],
+        [0x1a749d7964af0b72, 0x02913ef204c653f2, 0xb4bfb65ceab7b685, 0x233ab59ce3bb6925],
+        [0x18ae590073f96969, 0x6af762ffa4e8f0eb, 0xbf97f8cc787e37cd, 0xdd1f321be3beadbb],
+        [0x21c47b275d82dde6, 0x460d5e769a994211, 0x44b1c5a9da59294a, 0xde9cbb317103f249],
+        [0x0473ddbd52e737e5, 0x27364e8eb6320797, 0x5c38d5fd6cc32b27, 0x2102b082cd1518fb],
+    ],
+    [
+        [0x0b12fac95b6d3a88, 0x1d892657c8424e64, 0x5ac4e6b00515f902, 0xd594574302b26e02],
+        [0x08ae7616a260cf66, 0x57f8f73ac284588d, 0x2c5f07ff425d837a, 0xa7cdcef63e3e2103],
+        [0x039daf6876280b80, 0xe873bf2a32fd2834, 0xa83c69757badd58a, 0x888ef819e926ce28],
+        [0x25e7b1d7470a3c75, 0xf13f0b56546c8e09, 0xf2d8efeff06ef766, 0xf9c783ca869d130d],
+        [0x1e8fd3634c3ff764, 0x184d03435f98584b, 0x11b5b15aeb9c7526, 0x2da3f1ea2c2a9e7a],
+        [0x241dcc51ac37808a, 0x415dd1e3c281f05a, 0xff11789dc0cafdd7, 0x7a35484e0993f9a4],
+    ],
+    [
+        [0x1ffc3153c56ef975, 0x5932cea2be057374, 0x9bdafe1c4fa0781a, 0x4b8b4078ce9d7547],
+        [0x17630d62d9a3e510, 0xc88a4d43c360f92b, 0xc0fa00b66031adec, 0x29bd9543fd3a17ee],
+        [0x2980400edd1d74e3, 0xd69db5458d2ccd5f, 0xabdb236ec16a82a4, 0x301a0ab59ea4a6e9],
+        [0x3034fb24366123ec, 0x6dcafcad35726dbf, 0xb16194c036dcd648, 0xfa69439bfcd00cd4],
+        [0x1aa7e8f4189ca9df, 0xf3db2ab7648be0a2, 0x392995ce46041e04, 0x680dca8ad7232df0],
+        [0x1fa195f834a69e62, 0x372f60eb497da167, 0x646eae14153d803b, 0x39dc5d11f5d7800b],
+    ],
+    [
+        [0x0f23f1c74d5fbf61, 0x95ad5a6aee5e5699, 0x3c5477e8453f5b93, 0xa0d7bafd333036d3],
+        [0x016556fac9348a73, 0x5ab50aa089c97151, 0xb3caaf0a20a34fb9, 0xd9370505a1515729],
+        [0x23d92b793648110f, 0xc5aeef0633f0c77c, 0xacb0dbbca1879b8a, 0x6f6e5df445e5f70b],
+        [0x2e4c10ec5e65e2f2, 0x39bbc43c13031df2, 0x686ab40fd79a304b, 0x05d611b823f23b73],
+        [0x12418bbfd77b63ad, 0x5e16864ad9c32ffb, 0xfc5a3dd9b78ec2b7, 0x9329fe5e0a8d2953],
+        [0x1e4a8aace15abc1d, 0x5b76a9e848431d2c, 0x06a78f72b6bebb12, 0x93e6c58e5185696d],
+    ],
+    [
+        [0x0f3e96107decdbd6, 0x872c20ea09acf92c, 0xdf17a3ee1d133148, 0x8092d96176deb755],
+        [0x012c3780207f395c, 0xc21deb0abd951681, 0xeea32498ddba6ce8, 0x97a8f9f0c2357067],
+        [0x13eab1b4e672ba1b, 0x1c1bb901769301f1, 0xe5659d03ea10c61d, 0xe2477ff0ac221421],
+        [0x20dc664abb20b745, 0x6c06629ce37a1ecb, 0x1a27a4e8b24e31b4, 0x8b9c4635aa30323e],
+        [0x2c6b1e2cfea78e2c, 0x36785e76a8cfb1b0, 0x57e9471f24f5b391, 0x175c3decb01e000f],
+        [0x188c926255f5b7af, 0x3da96355729c2a86, 0x70ab4c2c70400481, 0xb2ac903740e0c5ab],
+    ],
+    [
+        [0x2f9913208e09e3d6, 0xe9e6fba6384fd076, 0xab89f2662976e3e3, 0x0e0870bb30eb54f2],
+        [0x2b33803d90889706, 0xe714f720b5628d26, 0xfb60b545a1f3e9ce, 0x49a6ae912b024086],
+        [0x26ccabc10eb04327, 0xcb5cc3dde2abb36f, 0x097086c97e738c13, 0x3c9f57077e748b09],
+        [0x1b16ae0d7c54408c, 0xb75fd931f246751f, 0x2b0c3dc20d79e82a, 0x2531b76c22b4d5df],
+        [0x11d0bb461bd8af28, 0x44f49f0f840c94ef, 0x9518b2511344742d, 0x1f5438fe3d415ae4],
+        [0x233031847b476bea, 0xd0118d3db338e891, 0x33ec420d673e504a, 0xd647259df655571e],
+    ],
+    [
+        [0x1f84e97895bee438, 0xeb3c92dc9b1846c9, 0xad29c164387b06ae, 0xb6ed1841ed8c4dca],
+        [0x277f7f9b542f0c2b, 0xb5f45bed054f0962, 0x4536010c3cf9452d, 0x2273193327f801d6],
+        [0x1efc9c9069e5068b, 0xaac13d2e6645641b, 0x7d27e80fc2307716, 0x1535c44682ee57a9],
+        [0x0d6ec477761e2efb, 0xac4f14b3bf3d5257, 0xa99e64c3f25fe104, 0xfaf988b20fe5ff44],
+        [0x0e0e7d7c5501999b, 0x7d16173b59b7cae1, 0xf203bef21aebf002, 0x51881439ccf93013],
+        [0x217bef2f4f12c6dc, 0xc91c2058a23391cb, 0x77de53ca6e44dcdc, 0x6ea3d36fea326ea6],
+    ],
+    [
+        [0x05780c88adf01531, 0xb50f817e3fe44447, 0xd29b35aa8a389c71, 0xe8cf1226acef68ba],
+        [0x18733887a6b2b3b4, 0xc90d8e4990196e23, 0x445e47d7ea5939eb, 0xfb89a3ee3d67b4bd],
+        [0x20bace63acfcae0b, 0x1c9f2bee24b8e9da, 0x85ba597d37b09057, 0x20c4f15db231b07a],
+        [0x166ea595375a6786, 0xac527ee9eced73ed, 0x6bf550876abcaf3a, 0xc92b42c808b00d8f],
+        [0x304262a9eff4040a, 0xcf43e322d6f52676, 0xae2f853ec2e7a80d, 0xb00c488cf917c74e],
+        [0x226bac7050166e5f, 0x6db78cd0b12d36f3, 0x05b6e8c9a055114a, 0xd7709e6f57245b6b],
+    ],
+    [
+        [0x26b2f539c573829f, 0x6aca91baa954505b, 0xc5c3eb4e1df1d638, 0x582717fbdf2388cc],
+        [0x06a0fbf4cd52e93b, 0xa5e4c6c4af65db02, 0xee96297f8ad200f2, 0xf1cff252e769b551],
+        [0x2cb9c24112d35341, 0xaceac88360fb5289, 0x24e58b6ecac321b9, 0xfb29e6aa3368ff23],
+        [0x20e88a4d607526dd, 0x07fe08a3552a4466, 0x9129eb87fcc0b13a, 0xac8fe8afd9301521],
+        [0x1544649a2bd73e3b, 0xa72f396df91dd654, 0x01dd8faf51de325f, 0xbaedb9d536ad94fc],
+        [0x1980077457995712, 0xc44da7e17713258e, 0x3f8eb354bfd80ed9, 0xeaf3ecbaf6960105],
+    ],
+    [
+        [0x25d1d22ff13e7705, 0xd3c085f97fc4e4f6, 0x914b82ffaa5d2091, 0xec64dac423765ef7],
+        [0x2fec990ef556efe1, 0x035a464ff5581e74, 0x067445cd54abcaf6, 0xb8c0399fe0d24cfc],
+        [0x1bd9563506d9544e, 0xf3e4830e13545012, 0xc579379c2dcc1330, 0x416c4ae49bc4ec61],
+        [0x00affcd17ba6003c, 0x56dfa85571fc2973, 0x7b225a80d480e7dd, 0x7edec01f14f23010],
+        [0x23670dbaef966881, 0xf07f919a2d883128, 0xc7b23cf767a477b2, 0xb2e0762bc0dbc18b],
+        [0x1f93a532917394c7, 0xe22fd17abeea6389, 0xc66fd7ae2dd9f02f, 0x860f6d96947f0edd],
+    ],
+    [
+        [0x2de42e9f537b7d61, 0xb0213771c0e74f55, 0x5512be07b6a50934, 0x734e2c5beb40be37],
+        [0x25c557f45b99781c, 0xd37d3bb22931662a, 0x67f78b37782c885b, 0x456bb96d55e88404],
+        [0x2074c8b709705c98, 0x88538a7f8a3c4aff, 0x647731bd16f8e254, 0xfa74ea9f2be7662c],
+        [0x2738355956298138, 0x949e442171d6a4e4, 0xb74ef2065740db7c, 0xfc3a0b60fd573acb],
+        [0x13d36ad0a4ebeb81, 0x969778649659c65c, 0xb7d0c41cc519871f, 0xdb71a9ea6a0caa56],
+        [0x08a2c18ba4138134, 0x8c1acfbf96177168, 0x06b462a1691bc2e3, 0x43b79b8085e376b0],
+    ],
+    [
+        [0x059092fc395aed28, 0x5807bbf557ad9a10, 0x41f59c079822b108, 0x8457882fee7b612c],
+        [0x161924151b5a5ad2, 0xd8cac119522a991a, 0x906f15e8531dc705, 0x67f6b28371cc24e3],
+        [0x1c68ca8f7aa17659, 0x075405ef6341b8e6, 0x9a298b9a4d72f3bb, 0x854b309e4ba87a1b],
+        [0x27f5d03bca1c8207, 0xf7239a4b2cf73ae5, 0x59a15aa37e7bdddf, 0x3aab05eec5ce5592],
+        [0x0ecbff4846962a97, 0x5d347ea9a8fc465f, 0xb46861557622f2c2, 0x564a7e639833c169],
+        [0x277c4de2363d8b5b, 0x456cfc5a7ff8e46f, 0xf2ec8daa59855f5a, 0xd64bc0521f3ac567],
+    ],
+    [
+        [0x1b11862c52acd351, 0xb7a464793f4fbb57, 0xfec99f832b63226f, 0x95d175c8d2fc08b2],
+        [0x06a719c584c74ffb, 0xdd7218eb565cb4c8, 0xbd86c92e3dfb3c73, 0xe1527201aa51234e],
+        [0x230e4adeecb79987, 0x7f7ce9a58c836b99, 0xd533584a195c1d77, 0xa313abe1c7d126bd],
+        [0x10b109b864809c47, 0x67a133cce6cbad6c, 0x88628173b8ea51e8, 0xcca85830ca7de522],
+        [0x0e21117970dcfbd4, 0xb1526b253636f377, 0x538d3b4faaeb5a8b, 0x24bf6200d14cc591],
+        [0x2667349978401362, 0xf6b17939eeb0e64f, 0xf55607ebdb35c707, 0x1db46bb3e7ba4778],
+    ],
+    [
+        [0x05000fa5fda505e0, 0x29a13bfe304c267b, 0x0d86c72c039babf6, 0xd3ff02ee246be02e],
+        [0x264d9e094aed5f41, 0xa60242220a34a284, 0x089087b2436a9bfc, 0xe8174cc9be8c2e20],
+        [0x08076f9c4743de61, 0x30ff622cf401edd2, 0xc92f24bfe114f3c5, 0xe724891746315c47],
+        [0x132370abddbb0b1d, 0xd57f2a520c25336b, 0xd7cede94b95bbf5c, 0x2151d6d88e641b64],
+        [0x08ff1116b7a227bf, 0xdfd4465a67890882, 0xb615c8c4c17f28d8, 0xd24958edf602ddcb],
+        [0x2bcb0b0db8b9e3e0, 0x2b7e9c1c9460fdd9, 0xc6cd98562332e648, 0xd8a3e0ab94597520],
+    ],
+    [
+        [0x12ea68ce6881beca, 0xd7f8a6b117b03ab9, 0x76f7abd597f903b0, 0xbf230d20d21a943a],
+        [0x27439c98a7668806, 0x7a097b19b6fdd7d7, 0x8d5f88e274e0d8fe, 0xa5ea6b7406fdda7f],
+        [0x02f40d0ad05f5652, 0xe31ef9440ad71ebc, 0x8419e393493937f0, 0x5f00499d02a99e36],
+        [0x2fbf04284327ee4f, 0x680f06bd390e309d, 0x0d13acc74b9c5b14, 0xb63059b8cc7abff5],
+        [0x1be686d53e2a8ad5, 0x7a828b0651425cfc, 0x6978c7027edbf247, 0xf6b6723c21df86e7],
+        [0x2683b425e85a508f, 0x96852f14b4220fcf, 0xe9f7ad8b17bfefc0, 0xe348c47ca78bb57f],
+    ],
+    [
+        [0x16dace9b2e8012e3, 0x1db1c7ebe672d86b, 0xbe61a1aa3e1693e0, 0xeddfc0de0a9dd951],
+        [0x27a321f8c7d3c902, 0x2e962f7fef2e3c84, 0x8b4539dbb75aa139, 0xf30430fe545bcedb],
+        [0x06ccd7210dee1d6b, 0x0e22b79e12d19082, 0xd8078b788d71007b, 0x95e7a774ed86a651],
+        [0x0a41dd4222165375, 0x2bef350f6d74a917, 0xb6cbb1fd76a3a121, 0x66f4d0be978e4026],
+        [0x220a02881e4d47ac, 0x94d950cdf8386274, 0xd1782e27cbd0d845, 0x978deec91298f165],
+        [0x0e2155a545fe5f3c, 0xbb6397606589eac1, 0x9cd92639339c6b01, 0x7298a4ad3408b4b9],
+    ],
+    [
+        [0x0f0f19c6291e5154, 0x6a267c60cc774e5f, 0xb9d088bac530782d, 0x891ec1af4b847073],
+        [0x0e925bcd1c6ddb4a, 0x3a1c67ec8deefbd4, 0x0c53c0d33e7aeef1, 0xb46795aed5943c9d],
+        [0x2ad000b1748abb81, 0x2cd6e5411286b9ff, 0x3ef0a5bd3d259a36, 0xe45ef05b9eb5beeb],
+        [0x0a65aa232d32ed6e, 0x8de63d1cdffebc2f, 0x3fa616465c27aaf9, 0x7e8cd3dcff648652],
+        [0x0263d8470ab4b1c6, 0x1d74d8e896242f4f, 0x261dcb167a3a0692, 0x3893d7cb2c93d6a1],
+        [0x2901d946addc94b0, 0x40fd58004d9a5f8c, 0xd1926540c7a8612c, 0xec1c58cb60c2b3a5],
+    ],
+    [
+        [0x1889cfa8209f4952, 0xdf9022db9dc583b5, 0x717a0696da41cee6, 0x4937d0cd6321e693],
+        [0x236064d71cb6c64c, 0x84747ac25fcf8d88, 0x1502e5f03bff8756, 0x1b85a116b1f39aca],
+        [0x2ff7a174ffcec298, 0x62e04f5dbdc73ebf, 0x3661570033576290, 0xc0c1f6cd8ced27ae],
+        [0x19e724a1d742cab1, 0x03455f0040edf745, 0xa2696a71084c93e3, 0x22715450dd4d6f5b],
+        [0x03eed3892b6f0e6c, 0x5da1059c5f379398, 0x5835aa283500a812, 0x9904a94c87f161bf],
+        [0x08e2b8273bfa30c1, 0xac850306d91e468a, 0x9e8d05092aee4cbc, 0x80c687248463ba30],
+    ],
+    [
+        [0x07adcca76d833772, 0x8839a1b6ac3b3ed4, 0x2afb87d72af98f52, 0xf416f2ec58b28cec],
+        [0x171ef37896bae2b1, 0x020a0a5839bd5178, 0x4ce11bb4237d548c, 0x171169d32fa19b40],
+        [0x20ffdfcb86f4d005, 0x064edbc296918c33, 0x2d32fbeff1729de5, 0x056a26abbc3a35fa],
+        [0x08ecd7a6f1735eed, 0x86baa094e608f488, 0xf38dbb398fcfed4b, 0x994383a0ca8e4647],
+        [0x1c3f5d86e5921fde, 0x9890189f1d8c6187, 0x54288600e6928bc1, 0x82ac4d5e4c9f0ccb],
+        [0x29c61184ed9d460f, 0x337558a1af639aa7, 0xe3c0975e4014ed8e, 0xbcad4a25d51eabf3],
+    ],
+    [
+        [0x0defd45b28958728, 0x228adbb2dbdaef6a, 0x5e9b1a64902a734f, 0x402b8cefb8ab3b56],
+        [0x0a74ea22d8a09336, 0x060610179ac1d82f, 0xffa9492df76deed4, 0xea60e0133b0811a8],
+        [0x03a37bf12daf1400, 0xd297ac4ac13ba24c, 0x17dc262db16c8523, 0xdeee4e0ccde9a680],
+        [0x11fe1790d5abbf59, 0x35ff22318e4f7ffe, 0x69966ada2f9136b5, 0x4f830eacb0a65368],
+        [0x018165842f406375, 0xf2346686915afb14, 0xbf1fe0564c8858ee, 0x3bde0aba3de5f68f],
+        [0x261db25e7cff5a9f, 0xb72f276b1f9260b6, 0x67300fb7d361b50f, 0xd5c0e8b6995b05f9],
+    ],
+    [
+        [0x2a3ac3314b2b66e7, 0x96fbe36df778c5e4, 0x6972320cc43ec807, 0x048826b6704ba7c4],
+        [0x23caa4b80ecfa99e, 0x9d3fea2bbc1dbbf3, 0x69d1bfc8937d03d0, 0x74061c30fd8cd76b],
+        [0x27db260085e22489, 0x8df145f23f635f20, 0x66d8e4e124e581e8, 0xc6261929b1dfe107],
+        [0x274f6c5fd34a784d, 0x6b915ef05d424ee6, 0xc0babbf369e79ab1, 0x38b8167b5618ec7f],
+        [0x2c3a29e13a84d26a, 0x0911c9289daf1aa4, 0xcf5840aada0701d5, 0x7e23dfc796da6da1],
+        [0x1ea210f2001a334d, 0x3e801f4e53270d42, 0xda7aaf317a553b42, 0x82aa78eaa2282e6d],
+    ],
+    [
+        [0x254dbeb52884b699, 0xc1ba7fa0d6e80d61, 0x0903b18a3e509c36, 0x351ccc3b024946e3],
+        [0x059e781d65896ebe, 0x0e4ba26dc2f29907, 0xf47bcdeda4a2ca2c, 0x713d8505ea31fd5d],
+        [0x0b5b1cec63d42d5e, 0x615dc269b885a24c, 0xef303ec78c972dd1, 0x7cdbb3e915cc4ffb],
+        [0x2a7c015e9c3b2c57, 0xca8b7d26d39a1bcc, 0x85d6ffacb7d9fbd6, 0x6d2a8f1d64ed0c92],
+        [0x29b736b911d71a79, 0xcf63d8a6f786f11b, 0xd5abee24161dc567, 0xa7c851eae1e43b51],
+        [0x285745a90a7fe3d0, 0x9af5a808704bc69c, 0x6f1701e573912df5, 0xcc1e265d596c4141],
+    ],
+    [
+        [0x2d901b8195c3c96c, 0x8c36eb99fec0134e, 0xc2b8304ae810bd30, 0xda554e3080826715],
+        [0x1905d3518355eaba, 0x7859b591ed7b8c9c, 0x253980f0450dbdf5, 0x4d7a7782ba058392],
+        [0x23e813026fc0b800, 0x64d19b5c5428942f, 0xdf7efea80bfa8ec4, 0x095272bfdb7b4c9f],
+        [0x23c0a19a252c87e6, 0xb1c1c21b1a798002, 0x00c3fbff3e3300e7, 0xe5568071de9efb81],
+        [0x11c4ae607bae4924, 0x13bf62cdaa2c2868, 0xed1fec6dc0631b06, 0x7ca60fab125b9e2a],
+        [0x2cd055ebb7ee4686, 0x365dea450f046ff6, 0x2405fae1b1afc9fb, 0x01707cf81da0e3b9],
+    ],
+    [
+        [0x053c9fef2e021fa9, 0xa20fada22fdea150, 0x5b58a3159bbb4733, 0x7dbf791b215b1452],
+        [0x0a35bd74e87cbaba, 0xabe89ad1319d2c9e, 0x863b4c631c21938c, 0x9a5395bf97872a9f],
+        [0x1c115056539ce20c, 0xd5a04d1a5c43e2b0, 0x0fbe83b25901be36, 0xf5ddc4666fc383fe],
+        [0x242954047e5772fd, 0x3bded590ec8beb4c, 0x542f2e264c8c3e28, 0x4cdc473505c51a90],
+        [0x0e2abd315b47c0dc, 0x93849c0cdf267e81, 0x1cbdbdb200a6e7c2, 0xb67edf7cb0174214],
+        [0x282b37020c0890d7, 0x51c3fd76950d8068, 0x668e1dfeae621dd5, 0x52d2de887da2ea75],
+    ],
+    [
+        [0x28933852266b52d9, 0xea6b5bb923d9d94f, 0x2e5a5be5c778e75e, 0x07942c234b643bd9],
+        [0x099ab6765505ba11, 0x98ef140e77b7954d, 0x4fbe79a056ce72ba, 0xce39c048c00da3cf],
+        [0x2af211d8e0ac2d8f, 0xda7f849b8f229a22, 0x5c6186b55762c7a7, 0xb2ae2d1dd85c57cb],
+        [0x0cd070f234014a28, 0x09ab90c71c1da61e, 0x98a96322fedd99b6, 0xaaae1ca104f3facf],
+        [0x26793e2abc8d3c30, 0xc60626fbaa158f26, 0x3587d6bd15833d44, 0x8b116264b930256a],
+        [0x225be36ed0ee85e1, 0xf845ada84e5748a5, 0x6699152113ff61b5, 0x056b6d8bde60c19d],
+    ],
+    [
+        [0x02174f49edb02d51, 0x54d2beca2dc92b9c, 0xc595383da1fde8f0, 0x9e4b5ee3ea5a065e],
+        [0x0f64891c2c8b020e, 0x46c3594cb758f0bd, 0xdcdbd09bd0308816, 0xfb41734a869872c3],
+        [0x192a84ca2f99d369, 0x91e2d2b1deff3989, 0xd1c156c239e10e9f, 0x56140e1854576067],
+        [0x29dfcd7b63f05abf, 0x2753a8c341d6b7a6, 0x0c6243b04c9a1b8b, 0x3320bba04a4d4787],
+        [0x1ee27ad6b9b5a867, 0x733afc61a2b3e76a, 0x52ba3e4bd5e657ad, 0xe91fc038819dba5b],
+        [0x0ab4773f150c3f8a, 0xd3bc9538f43cec39, 0x5a7e3731ae973fef, 0xeb623a09217e64c7],
+    ],
+    [
+        [0x13c352a02f595186, 0x202cb0b99fa58c55, 0x42ab67f9b6d3a0af, 0xd103deeff6d80f41],
+        [0x2a97cf2c10c4bfbf, 0xd299f67c52a169f9, 0x2c05b7dac56a41c4, 0xdd4fe87c8246ce14],
+        [0x00becbb47042bd7f, 0x8c9f6bb422162d1a, 0xed089a28482f7fd1, 0x6ab06a13285fe702],
+        [0x008e44da21d73869, 0x1b881757ef37ed29, 0xc5bd9f7a4450fcf5, 0x3290a92cc2ca2176],
+        [0x2b205a8b6d4b7063, 0xd931f3bb5d346405, 0x3843fe7fbe4b83c1, 0x7883f86527882a18],
+        [0x2d9e32a7c90556fe, 0x108d255ac01e75df, 0x338fcd63b2bf84c1, 0x9280d427298863fc],
+    ],
+    [
+        [0x29a322a84c25bd2d, 0xdf6e2e4200228d95, 0xabd6349a02266ac1, 0xdbba520738ceca97],
+        [0x0678c9bfc6f2df01, 0x2f4fe55e33bb68ac, 0x14ced1df0d021527, 0x92089d046d828c43],
+        [0x0faff3a5e7425794, 0xfe20a7e0eb615b8b, 0x1760394b7f230428, 0x6a3ae4009124db23],
+        [0x1f8f5b611af9feb9, 0xcea86c0840581205, 0x53e404103aee213f, 0x5a41d1d02541c0d3],
+        [0x160875d8479602f9, 0x6f40acc2d042ee52, 0xc1588b6a29de4284, 0x965a6dc6c930ea07],
+        [0x16d87a5183a316a1, 0xd70afc951efe2cd6, 0x67c77328fcfda458, 0xcbf5fe3045f46d9e],
+    ],
+];
@@ -0,0 +1,75 @@
+//! Scoring rules for each `GameMode`.
+//!
+//! | Mode     | Levels | Best attempt per level | Session standing               | Leaderboard   |
+//! |----------|--------|------------------------|--------------------------------|---------------|
+//! | Race     | 3      | lowest time            | most levels, then lowest total | ascending     |
+//! | Survival | 1      | highest time           | highest time                   | descending    |
+//!
+//! Ties go to whoever reached the tied standing first: in a session, the
+//! player whose deciding run landed in an earlier ledger; on the leaderboard,
+//! the entry that was already there.
+//!
+//! `zk/cubeathon_circuit` ties `time_ms` to the path: the track passes a
+//! wall every 500 ms, and the proof must clear every wall reached within the
+//! claimed time, up to the circuit's `MAX_WALLS`. A longer time needs a
+//! longer collision-free path, so a proven Survival time, where the highest
+//! time ranks first, can go on the leaderboard. (The proof shows that such a
+//! path exists on the seeded track, not who or what steered it.)
+//!
+//! A shorter time needs less path, so under Race, where the lowest time
+//! ranks first, a proof for any time (down to 0) is as easy to make as an
+//! honest one. Race runs therefore never count as proven for the
+//! leaderboard: they still settle sessions, but reach the boards only under
+//! `ProofPolicy::Dev`, like any other unproven run.
+
+use core::cmp::Ordering;
+
+use crate::{GameMode, PlayerProgress};
+
+impl GameMode {
+    /// Number of levels a player must clear to finish the session.
+    pub fn level_count(self) -> u32 {
+        match self {
+            GameMode::Race     => 3,
+            GameMode::Survival => 1,
+        }
+    }
+
+    /// True if `new_ms` beats the previous best `old_ms` on the same level.
+    pub fn improves(self, new_ms: u64, old_ms: u64) -> bool {
+        match self {
+            GameMode::Race     => new_ms < old_ms,
+            GameMode::Survival => new_ms > old_ms,
+        }
+    }
+
+    /// Session score: total time over the best run of every cleared level.
+    pub fn score(self, progress: &PlayerProgress) -> u64 {
+        progress.total_time_ms()
+    }
+
+    /// Compare two players' standings, ignoring ties. `Greater` means `a` is ahead.
+    pub fn compare(self, a: &PlayerProgress, b: &PlayerProgress) -> Ordering {
+        match self {
+            GameMode::Race => a.levels.len().cmp(&b.levels.len())
+                .then_with(|| self.score(b).cmp(&self.score(a))),
+            GameMode::Survival => self.score(a).cmp(&self.score(b)),
+        }
+    }
+
+    /// True if a verified proof is enough to put a run on the leaderboard.
+    pub fn ranks_proofs(self) -> bool {
+        match self {
+            GameMode::Race     => false,
+            GameMode::Survival => true,
+        }
+    }
+
+    /// True if a leaderboard score `a` ranks strictly ahead of `b`.
+    pub fn ranks_ahead(self, a: u64, b: u64) -> bool {
+        match self {
+            GameMode::Race     => a < b,
+            GameMode::Survival => a > b,
+        }
+    }
+}
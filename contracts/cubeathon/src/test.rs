@@ -0,0 +1,2375 @@
+#![cfg(test)]
+
+// Unit tests for the cubeathon contract using a mock GameHub and a mock
+// verifier. Proof checking itself is covered by the zk-verifier crate; here a
+// "proof" is simply the journal it commits to, which lets the tests check
+// exactly which journal and image id the game hands to the verifier.
+
+use crate::{
+    commitment, leaderboard::{self, BoardIndex, BucketInfo}, poseidon, Board, CancelledBy, DrawReport,
+    CubeathonContract, CubeathonContractClient, DataKey, Difficulty, Error, GameMode, GameStatus,
+    LeaderboardEntry, ProofPolicy, LEADERBOARD_MAX,
+    ScoreUpdated, Season, SeasonStarted, SeedCommitments, SessionCancelled, SessionDeadline,
+    SessionForfeited, SessionOutcome, SessionSettled, SessionStarted, TieBreak,
+};
+use soroban_sdk::testutils::{
+    storage::{Persistent as _, Temporary as _}, Address as _, AuthorizedFunction, AuthorizedInvocation, Events as _,
+    Ledger as _, MockAuth, MockAuthInvoke,
+};
+use soroban_sdk::{
+    contract, contractimpl, vec, Address, Bytes, BytesN, Env, Event, IntoVal,
+    Symbol, Val, Vec, U256,
+};
+
+// ============================================================================
+// Mock GameHub and Verifier for Unit Testing
+// ============================================================================
+
+#[contract]
+pub struct MockGameHub;
+
+#[contractimpl]
+impl MockGameHub {
+    pub fn start_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+        // Mock implementation - does nothing
+    }
+
+    /// Records the result so tests can check what the hub was told.
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) {
+        env.storage().instance().set(&session_id, &player1_won);
+    }
+
+    pub fn result(env: Env, session_id: u32) -> Option<bool> {
+        env.storage().instance().get(&session_id)
+    }
+}
+
+#[contract]
+pub struct MockVerifier;
+
+#[contractimpl]
+impl MockVerifier {
+    /// Registers `image_id` as circuit `version`.
+    pub fn __constructor(env: Env, image_id: BytesN<32>, version: u32) {
+        env.storage().instance().set(&image_id, &version);
+    }
+
+    /// Accepts a proof iff it equals `journal_hash` and `image_id` is the one
+    /// the mock was deployed with.
+    pub fn verify(env: Env, proof: Bytes, image_id: BytesN<32>, journal_hash: BytesN<32>) {
+        assert!(env.storage().instance().has(&image_id), "unknown image id");
+        assert_eq!(proof, Bytes::from(journal_hash), "journal mismatch");
+    }
+
+    pub fn get_circuit_version(env: Env, image_id: BytesN<32>) -> Option<u32> {
+        env.storage().instance().get(&image_id)
+    }
+}
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+const POINTS: i128 = 100_0000000;
+
+struct Setup {
+    env:      Env,
+    client:   CubeathonContractClient<'static>,
+    player1:  Address,
+    player2:  Address,
+}
+
+fn image_id(env: &Env, tag: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[tag; 32])
+}
+
+/// Deploy cubeathon against a verifier that accepts image id `verifier_image`.
+fn setup_with_verifier(verifier_image: u8) -> Setup {
+    setup_with(verifier_image, ProofPolicy::Required)
+}
+
+fn setup_with(verifier_image: u8, proof_policy: ProofPolicy) -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1441065600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    let hub_addr = env.register(MockGameHub, ());
+    // The mock registers its image under a version equal to the image tag.
+    let verifier_addr = env.register(MockVerifier, (image_id(&env, verifier_image), verifier_image as u32));
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(
+        CubeathonContract,
+        (&admin, &hub_addr, &verifier_addr, image_id(&env, 1), proof_policy),
+    );
+    let client = CubeathonContractClient::new(&env, &contract_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    Setup { env, client, player1, player2 }
+}
+
+fn setup_test() -> Setup {
+    setup_with_verifier(1)
+}
+
+fn nonce(env: &Env) -> BytesN<32> {
+    let mut bytes = [0u8; 32];
+    bytes[31] = 42;
+    BytesN::from_array(env, &bytes)
+}
+
+/// Mock proof committing to an explicit journal domain and track seed.
+fn proof_for(
+    env: &Env,
+    domain: &BytesN<32>,
+    seed: &BytesN<32>,
+    player: &Address,
+    time_ms: u64,
+    nonce: &BytesN<32>,
+) -> Bytes {
+    commitment::outcome_hash(env, domain, seed, player, time_ms, nonce).into()
+}
+
+/// Mock proof for a run of `time_ms` by `player` on the track with `seed`.
+fn proof_with_seed(
+    client: &CubeathonContractClient,
+    seed: &BytesN<32>,
+    player: &Address,
+    time_ms: u64,
+) -> Bytes {
+    let env = &client.env;
+    proof_for(env, &client.get_journal_domain().unwrap(), seed, player, time_ms, &nonce(env))
+}
+
+/// Track seed of `level` in `session_id`.
+fn track(client: &CubeathonContractClient, session_id: u32, level: u32) -> BytesN<32> {
+    let state = client.get_game(&session_id).unwrap();
+    commitment::level_seed(&client.env, &state.seed.unwrap(), state.mode, state.difficulty, level)
+}
+
+/// Mock proof for a run of `time_ms` by `player` on `level` of `session_id`.
+fn proof(
+    client: &CubeathonContractClient,
+    session_id: u32,
+    player: &Address,
+    level: u32,
+    time_ms: u64,
+) -> Bytes {
+    proof_with_seed(client, &track(client, session_id, level), player, time_ms)
+}
+
+/// Clear every level in order with the given times, with or without proofs.
+fn clear_levels(
+    client: &CubeathonContractClient,
+    session_id: u32,
+    player: &Address,
+    times: [u64; 3],
+    proven: bool,
+) {
+    let env = &client.env;
+    for (i, time_ms) in times.into_iter().enumerate() {
+        let level = i as u32 + 1;
+        let proof = if proven { proof(client, session_id, player, level, time_ms) } else { Bytes::new(env) };
+        assert!(client.submit_score(&session_id, player, &level, &time_ms, &nonce(env), &proof));
+    }
+}
+
+/// What the Game Hub was told about `session_id`, if anything.
+fn hub_result(client: &CubeathonContractClient, session_id: u32) -> Option<bool> {
+    let hub: Address = client.env.as_contract(&client.address, || {
+        client.env.storage().instance().get(&DataKey::GameHubAddress).unwrap()
+    });
+    MockGameHubClient::new(&client.env, &hub).result(&session_id)
+}
+
+/// Assert that a Result contains a specific cubeathon error
+fn assert_cubeathon_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => assert_eq!(*actual_error, expected_error),
+        _ => panic!("Expected contract error {:?} (code {})", expected_error, expected_error as u32),
+    }
+}
+
+// ============================================================================
+// Verifier Image Tests
+// ============================================================================
+
+#[test]
+fn test_submission_uses_configured_image_id() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    let proof = proof(&client, 1, &player1, 1, 5_000);
+    assert!(client.submit_score(&1, &player1, &1, &5_000, &nonce(&env), &proof));
+}
+
+#[test]
+fn test_admin_rotates_image_id() {
+    let Setup { env, client, player1, player2 } = setup_with_verifier(2);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    // The verifier only knows image 2, so image 1 has no circuit version and
+    // proven runs are refused until the admin rotates.
+    assert_eq!(client.get_circuit_version(), None);
+    let domain = env.as_contract(&client.address, || commitment::journal_domain(&env, 1));
+    let stale = proof_for(&env, &domain, &track(&client, 1, 1), &player1, 5_000, &nonce(&env));
+    let result = client.try_submit_score(&1, &player1, &1, &5_000, &nonce(&env), &stale);
+    assert_cubeathon_error(&result, Error::UnknownCircuit);
+
+    client.set_image_id(&image_id(&env, 2));
+    assert_eq!(client.get_image_id(), image_id(&env, 2));
+    assert_eq!(client.get_circuit_version(), Some(2));
+    let proof = self::proof(&client, 1, &player1, 1, 5_000);
+    assert!(client.submit_score(&1, &player1, &1, &5_000, &nonce(&env), &proof));
+}
+
+#[test]
+#[should_panic]
+fn test_set_image_id_requires_admin() {
+    let Setup { env, client, .. } = setup_test();
+    env.set_auths(&[]);
+
+    client.set_image_id(&image_id(&env, 1));
+}
+
+#[test]
+fn test_set_image_id_rejects_unknown_circuit() {
+    let Setup { env, client, .. } = setup_test();
+
+    let result = client.try_set_image_id(&image_id(&env, 2));
+    assert_cubeathon_error(&result, Error::UnknownCircuit);
+    assert_eq!(client.get_image_id(), image_id(&env, 1));
+    assert_eq!(client.get_circuit_version(), Some(1));
+}
+
+#[test]
+fn test_constructor_reads_circuit_version_from_verifier() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let hub_addr = env.register(MockGameHub, ());
+    let verifier_addr = env.register(MockVerifier, (image_id(&env, 1), 7u32));
+    let contract_id = env.register(
+        CubeathonContract,
+        (&admin, &hub_addr, &verifier_addr, image_id(&env, 1), ProofPolicy::Required),
+    );
+    let client = CubeathonContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.get_circuit_version(), Some(7));
+    let domain = env.as_contract(&contract_id, || commitment::journal_domain(&env, 7));
+    assert_eq!(client.get_journal_domain(), Some(domain));
+}
+
+// ============================================================================
+// Proof Policy Tests
+// ============================================================================
+
+#[test]
+fn test_required_policy_rejects_empty_proof() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    let result = client.try_submit_score(&1, &player1, &1, &5_000, &nonce(&env), &Bytes::new(&env));
+    assert_cubeathon_error(&result, Error::ProofRequired);
+}
+
+#[test]
+fn test_optional_policy_keeps_unverified_runs_off_leaderboard() {
+    let Setup { env, client, player1, player2 } = setup_with(1, ProofPolicy::Optional);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    clear_levels(&client, 1, &player1, [5_000, 6_000, 7_000], false);
+    assert!(!client.get_game(&1).unwrap().p1_progress.verified());
+    client.submit_score(&1, &player2, &1, &9_000, &nonce(&env), &Bytes::new(&env));
+
+    pass_deadline(&env);
+    assert_eq!(client.end_session(&1), SessionOutcome::Winner(player1.clone()));
+    assert!(client.get_leaderboard(&GameMode::Race).is_empty());
+}
+
+#[test]
+fn test_optional_policy_lists_verified_runs() {
+    let Setup { client, player1, player2, .. } = setup_with(1, ProofPolicy::Optional);
+    win_survival(&client, 1, &player1, &player2, 5_000);
+    assert_eq!(client.get_leaderboard(&GameMode::Survival).len(), 1);
+}
+
+#[test]
+fn test_proven_race_runs_stay_off_leaderboard() {
+    // The circuit does not constrain a run's time, so Race only ranks in dev mode.
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    clear_levels(&client, 1, &player1, [5_000, 6_000, 7_000], true);
+
+    pass_deadline(&env);
+    assert_eq!(client.end_session(&1), SessionOutcome::Winner(player1.clone()));
+    assert!(client.get_leaderboard(&GameMode::Race).is_empty());
+    assert!(board_of(&client, GameMode::Race, Difficulty::Normal, 1).is_empty());
+}
+
+#[test]
+fn test_dev_policy_lists_unverified_runs() {
+    let Setup { env, client, player1, player2 } = setup_with(1, ProofPolicy::Dev);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    clear_levels(&client, 1, &player1, [5_000, 6_000, 7_000], false);
+
+    pass_deadline(&env);
+    client.end_session(&1);
+    assert_eq!(client.get_leaderboard(&GameMode::Race).len(), 1);
+}
+
+#[test]
+fn test_dev_policy_still_verifies_supplied_proofs() {
+    let Setup { env, client, player1, player2 } = setup_with(1, ProofPolicy::Dev);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    let proof = proof(&client, 1, &player1, 1, 5_000);
+    assert!(client.try_submit_score(&1, &player1, &1, &9_000, &nonce(&env), &proof).is_err());
+}
+
+#[test]
+fn test_admin_sets_proof_policy() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    client.set_proof_policy(&ProofPolicy::Optional);
+    assert_eq!(client.get_proof_policy(), ProofPolicy::Optional);
+    assert!(client.submit_score(&1, &player1, &1, &5_000, &nonce(&env), &Bytes::new(&env)));
+}
+
+#[test]
+#[should_panic]
+fn test_set_proof_policy_requires_admin() {
+    let Setup { env, client, .. } = setup_test();
+    env.set_auths(&[]);
+
+    client.set_proof_policy(&ProofPolicy::Dev);
+}
+
+#[test]
+fn test_score_event_reports_verification_status() {
+    let Setup { env, client, player1, player2 } = setup_with(1, ProofPolicy::Optional);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    client.submit_score(&1, &player1, &1, &5_000, &nonce(&env), &Bytes::new(&env));
+    let unverified = ScoreUpdated { session_id: 1, player: player1.clone(), level: 1, time_ms: 5_000, verified: false };
+    assert_eq!(env.events().all().filter_by_contract(&client.address), [unverified.to_xdr(&env, &client.address)]);
+
+    let proof = proof(&client, 1, &player2, 1, 6_000);
+    client.submit_score(&1, &player2, &1, &6_000, &nonce(&env), &proof);
+    let verified = ScoreUpdated { session_id: 1, player: player2.clone(), level: 1, time_ms: 6_000, verified: true };
+    assert_eq!(env.events().all().filter_by_contract(&client.address), [verified.to_xdr(&env, &client.address)]);
+}
+
+// ============================================================================
+// Replay Tests
+// ============================================================================
+
+#[test]
+fn test_journal_cannot_be_replayed() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    let proof = proof(&client, 1, &player1, 1, 5_000);
+    assert!(client.submit_score(&1, &player1, &1, &5_000, &nonce(&env), &proof));
+
+    let result = client.try_submit_score(&1, &player1, &1, &5_000, &nonce(&env), &proof);
+    assert_cubeathon_error(&result, Error::JournalReplayed);
+}
+
+#[test]
+fn test_nullifiers_live_as_long_as_their_session() {
+    let Setup { env, client, player1, player2 } = setup_with(1, ProofPolicy::Optional);
+    env.ledger().with_mut(|l| l.min_temp_entry_ttl = 100);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    let proof = proof(&client, 1, &player1, 1, 5_000);
+    client.submit_score(&1, &player1, &1, &5_000, &nonce(&env), &proof);
+
+    // A later run without a proof extends the session, and with it the
+    // journals the session has already consumed.
+    env.ledger().with_mut(|l| l.sequence_number += 1_000);
+    client.submit_score(&1, &player2, &1, &6_000, &nonce(&env), &Bytes::new(&env));
+    let ttl = |key: &DataKey| env.as_contract(&client.address, || env.storage().temporary().get_ttl(key));
+    assert_eq!(ttl(&DataKey::Nullifiers(1)), ttl(&DataKey::Game(1)));
+
+    let result = client.try_submit_score(&1, &player1, &1, &5_000, &nonce(&env), &proof);
+    assert_cubeathon_error(&result, Error::JournalReplayed);
+}
+
+#[test]
+fn test_fresh_nonce_is_not_a_replay() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    let proof = proof(&client, 1, &player1, 1, 5_000);
+    assert!(client.submit_score(&1, &player1, &1, &5_000, &nonce(&env), &proof));
+
+    let seed = track(&client, 1, 1);
+    let other_nonce = BytesN::from_array(&env, &[7; 32]);
+    let domain = client.get_journal_domain().unwrap();
+    let other_proof = proof_for(&env, &domain, &seed, &player1, 5_000, &other_nonce);
+    assert!(client.submit_score(&1, &player1, &1, &5_000, &other_nonce, &other_proof));
+}
+
+#[test]
+fn test_rejected_proof_does_not_consume_journal() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    let proof = proof(&client, 1, &player1, 1, 5_000);
+    let bad_proof = Bytes::from_array(&env, &[0; 32]);
+    assert!(client.try_submit_score(&1, &player1, &1, &5_000, &nonce(&env), &bad_proof).is_err());
+    assert!(client.submit_score(&1, &player1, &1, &5_000, &nonce(&env), &proof));
+}
+
+// ============================================================================
+// Commitment Tests
+// ============================================================================
+
+#[test]
+fn test_poseidon_hash_5_matches_circomlib() {
+    let env = Env::default();
+    let inputs = [1u32, 2, 3, 4, 5].map(|x| U256::from_u32(&env, x));
+
+    // circomlib / Noir `poseidon::bn254::hash_5([1, 2, 3, 4, 5])`
+    let expected = U256::from_parts(
+        &env,
+        0x0dab9449e4a1398a,
+        0x15224c0b15a49d59,
+        0x8b2174d305a316c9,
+        0x18125f8feeb123c0,
+    );
+    assert_eq!(poseidon::hash_5(&env, inputs), expected);
+}
+
+#[test]
+fn test_player_id_is_canonical() {
+    let env = Env::default();
+    let player = Address::generate(&env);
+    let id: BytesN<32> = commitment::player_id(&env, &player).to_be_bytes().try_into().unwrap();
+    assert!(commitment::is_canonical(&id));
+}
+
+#[test]
+fn test_proof_for_other_time_rejected() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    // A valid proof for a 5s run cannot be used to claim 9s.
+    let proof = proof(&client, 1, &player1, 1, 5_000);
+    assert!(client.try_submit_score(&1, &player1, &1, &9_000, &nonce(&env), &proof).is_err());
+    assert_eq!(client.get_game(&1).unwrap().p1_progress.levels.len(), 0);
+}
+
+#[test]
+fn test_proof_for_other_player_rejected() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    let proof = proof(&client, 1, &player1, 1, 5_000);
+    assert!(client.try_submit_score(&1, &player2, &1, &5_000, &nonce(&env), &proof).is_err());
+}
+
+#[test]
+fn test_proof_for_other_session_rejected() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    client.start_game(&2, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    let proof = proof(&client, 1, &player1, 1, 5_000);
+    assert!(client.try_submit_score(&2, &player1, &1, &5_000, &nonce(&env), &proof).is_err());
+}
+
+#[test]
+fn test_non_canonical_nonce_rejected() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    let nonce = BytesN::from_array(&env, &[0xff; 32]);
+    let proof = proof(&client, 1, &player1, 1, 5_000);
+    let result = client.try_submit_score(&1, &player1, &1, &5_000, &nonce, &proof);
+    assert_cubeathon_error(&result, Error::InvalidNonce);
+}
+
+// ============================================================================
+// Session Seed Tests
+// ============================================================================
+
+#[test]
+fn test_start_game_stores_canonical_seed() {
+    let Setup { client, player1, player2, .. } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    let seed = client.get_game(&1).unwrap().seed.unwrap();
+    assert!(commitment::is_canonical(&seed));
+}
+
+#[test]
+fn test_sessions_get_distinct_seeds() {
+    let Setup { client, player1, player2, .. } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    client.start_game(&2, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    assert_ne!(client.get_game(&1).unwrap().seed, client.get_game(&2).unwrap().seed);
+}
+
+#[test]
+fn test_proof_for_other_track_rejected() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    // A run on a track the player picked (here: the session id, as the
+    // frontend used to do) does not match the on-chain seed.
+    let mut own_seed = [0u8; 32];
+    own_seed[28..].copy_from_slice(&1u32.to_be_bytes());
+    let proof = proof_with_seed(&client, &BytesN::from_array(&env, &own_seed), &player1, 5_000);
+    assert!(client.try_submit_score(&1, &player1, &1, &5_000, &nonce(&env), &proof).is_err());
+}
+
+#[test]
+fn test_proof_for_other_difficulty_rejected() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Hard, &None);
+
+    // Same session seed and level, but played as an Easy track.
+    let seed = client.get_game(&1).unwrap().seed.unwrap();
+    let easy = commitment::level_seed(&env, &seed, GameMode::Race, Difficulty::Easy, 1);
+    let proof = proof_with_seed(&client, &easy, &player1, 5_000);
+    assert!(client.try_submit_score(&1, &player1, &1, &5_000, &nonce(&env), &proof).is_err());
+
+    let proof = proof_with_seed(&client, &track(&client, 1, 1), &player1, 5_000);
+    assert!(client.submit_score(&1, &player1, &1, &5_000, &nonce(&env), &proof));
+}
+
+#[test]
+fn test_proof_for_other_mode_rejected() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+
+    let seed = client.get_game(&1).unwrap().seed.unwrap();
+    let race = commitment::level_seed(&env, &seed, GameMode::Race, Difficulty::Normal, 1);
+    let proof = proof_with_seed(&client, &race, &player1, 5_000);
+    assert!(client.try_submit_score(&1, &player1, &1, &5_000, &nonce(&env), &proof).is_err());
+}
+
+// ============================================================================
+// Commit-Reveal Seed Tests
+// ============================================================================
+
+fn entropy(env: &Env, tag: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[tag; 32])
+}
+
+fn commit(env: &Env, entropy: &BytesN<32>) -> BytesN<32> {
+    env.crypto().sha256(&entropy.clone().into()).into()
+}
+
+fn start_commit_reveal(setup: &Setup, session_id: u32) {
+    let Setup { env, client, player1, player2 } = setup;
+    let commitments = SeedCommitments {
+        p1_commit: commit(env, &entropy(env, 0xa1)),
+        p2_commit: commit(env, &entropy(env, 0xb2)),
+    };
+    client.start_game(&session_id, player1, player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &Some(commitments));
+}
+
+fn advance_time(env: &Env, secs: u64) {
+    env.ledger().with_mut(|l| l.timestamp += secs);
+}
+
+/// Move past the default session deadline so `end_session` settles unfinished play.
+fn pass_deadline(env: &Env) {
+    advance_time(env, 86_401);
+}
+
+#[test]
+fn test_commit_reveal_derives_seed_from_both_reveals() {
+    let setup = setup_test();
+    start_commit_reveal(&setup, 1);
+    let Setup { env, client, player1, player2 } = &setup;
+
+    assert_eq!(client.get_game(&1).unwrap().seed, None);
+    client.reveal_seed(&1, player1, &entropy(env, 0xa1));
+    assert_eq!(client.get_game(&1).unwrap().seed, None);
+    client.reveal_seed(&1, player2, &entropy(env, 0xb2));
+
+    let expected = commitment::revealed_seed(env, 1, &entropy(env, 0xa1), &entropy(env, 0xb2));
+    let seed = client.get_game(&1).unwrap().seed.unwrap();
+    assert_eq!(seed, expected);
+    assert!(commitment::is_canonical(&seed));
+
+    let proof = proof(client, 1, player1, 1, 5_000);
+    assert!(client.submit_score(&1, player1, &1, &5_000, &nonce(env), &proof));
+}
+
+#[test]
+fn test_commit_reveal_blocks_scores_until_revealed() {
+    let setup = setup_test();
+    start_commit_reveal(&setup, 1);
+    let Setup { env, client, player1, .. } = &setup;
+
+    client.reveal_seed(&1, player1, &entropy(env, 0xa1));
+    let result = client.try_submit_score(&1, player1, &1, &5_000, &nonce(env), &Bytes::new(env));
+    assert_cubeathon_error(&result, Error::SeedNotRevealed);
+    assert_cubeathon_error(&client.try_end_session(&1), Error::SeedNotRevealed);
+}
+
+#[test]
+fn test_reveal_must_match_commitment() {
+    let setup = setup_test();
+    start_commit_reveal(&setup, 1);
+    let Setup { env, client, player1, .. } = &setup;
+
+    let result = client.try_reveal_seed(&1, player1, &entropy(env, 0xb2));
+    assert_cubeathon_error(&result, Error::RevealMismatch);
+}
+
+#[test]
+fn test_reveal_only_once() {
+    let setup = setup_test();
+    start_commit_reveal(&setup, 1);
+    let Setup { env, client, player1, .. } = &setup;
+
+    client.reveal_seed(&1, player1, &entropy(env, 0xa1));
+    let result = client.try_reveal_seed(&1, player1, &entropy(env, 0xa1));
+    assert_cubeathon_error(&result, Error::AlreadyRevealed);
+}
+
+#[test]
+fn test_reveal_requires_commit_reveal_session() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    let result = client.try_reveal_seed(&1, &player1, &entropy(&env, 0xa1));
+    assert_cubeathon_error(&result, Error::NotCommitReveal);
+}
+
+#[test]
+fn test_reveal_after_deadline_rejected() {
+    let setup = setup_test();
+    start_commit_reveal(&setup, 1);
+    let Setup { env, client, player1, .. } = &setup;
+
+    advance_time(env, 3_601);
+    let result = client.try_reveal_seed(&1, player1, &entropy(env, 0xa1));
+    assert_cubeathon_error(&result, Error::RevealDeadlinePassed);
+}
+
+#[test]
+fn test_reveal_timeout_awards_revealing_player() {
+    let setup = setup_test();
+    start_commit_reveal(&setup, 1);
+    let Setup { env, client, player2, .. } = &setup;
+
+    client.reveal_seed(&1, player2, &entropy(env, 0xb2));
+    assert_cubeathon_error(&client.try_claim_reveal_timeout(&1), Error::RevealDeadlineNotReached);
+
+    advance_time(env, 3_601);
+    assert_eq!(client.claim_reveal_timeout(&1), Some(player2.clone()));
+    assert_eq!(client.get_game(&1).unwrap().winner, Some(player2.clone()));
+}
+
+#[test]
+fn test_reveal_timeout_without_reveals_expires() {
+    let setup = setup_test();
+    start_commit_reveal(&setup, 1);
+    let Setup { env, client, player1, .. } = &setup;
+
+    advance_time(env, 3_601);
+    assert_eq!(client.claim_reveal_timeout(&1), None);
+
+    let state = client.get_game(&1).unwrap();
+    assert_eq!(state.status, GameStatus::Expired);
+    assert_eq!(state.winner, None);
+    let result = client.try_submit_score(&1, player1, &1, &5_000, &nonce(env), &Bytes::new(env));
+    assert_cubeathon_error(&result, Error::SessionExpired);
+}
+
+// ============================================================================
+// Journal Domain Tests
+// ============================================================================
+
+#[test]
+fn test_journal_domain_is_canonical() {
+    let Setup { client, .. } = setup_test();
+    assert!(commitment::is_canonical(&client.get_journal_domain().unwrap()));
+}
+
+#[test]
+fn test_proof_for_other_contract_rejected() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    // A second deployment sharing the same hub and verifier.
+    let admin = Address::generate(&env);
+    let hub_addr = env.register(MockGameHub, ());
+    let verifier_addr = env.register(MockVerifier, (image_id(&env, 1), 1u32));
+    let other_id = env.register(
+        CubeathonContract,
+        (&admin, &hub_addr, &verifier_addr, image_id(&env, 1), ProofPolicy::Required),
+    );
+    let other = CubeathonContractClient::new(&env, &other_id);
+    assert_ne!(other.get_journal_domain().unwrap(), client.get_journal_domain().unwrap());
+
+    let seed = track(&client, 1, 1);
+    let proof = proof_for(&env, &other.get_journal_domain().unwrap(), &seed, &player1, 5_000, &nonce(&env));
+    assert!(client.try_submit_score(&1, &player1, &1, &5_000, &nonce(&env), &proof).is_err());
+}
+
+#[test]
+fn test_proof_for_other_network_rejected() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    let network_id = env.ledger().network_id();
+    env.ledger().set_network_id([9; 32]);
+    let other_domain = client.get_journal_domain().unwrap();
+    env.ledger().set_network_id(network_id.to_array());
+    assert_ne!(other_domain, client.get_journal_domain().unwrap());
+
+    let seed = track(&client, 1, 1);
+    let proof = proof_for(&env, &other_domain, &seed, &player1, 5_000, &nonce(&env));
+    assert!(client.try_submit_score(&1, &player1, &1, &5_000, &nonce(&env), &proof).is_err());
+}
+
+#[test]
+fn test_proof_for_old_circuit_version_rejected() {
+    let Setup { env, client, player1, player2 } = setup_with_verifier(2);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    // Made for version 1; the verifier only knows image 2, at version 2.
+    let domain = env.as_contract(&client.address, || commitment::journal_domain(&env, 1));
+    let proof = proof_for(&env, &domain, &track(&client, 1, 1), &player1, 5_000, &nonce(&env));
+    client.set_image_id(&image_id(&env, 2));
+    assert!(client.try_submit_score(&1, &player1, &1, &5_000, &nonce(&env), &proof).is_err());
+}
+
+// ============================================================================
+// Level Progression Tests
+// ============================================================================
+
+#[test]
+fn test_level_out_of_range_rejected() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    for level in [0u32, 4] {
+        let proof = proof(&client, 1, &player1, 1, 5_000);
+        let result = client.try_submit_score(&1, &player1, &level, &5_000, &nonce(&env), &proof);
+        assert_cubeathon_error(&result, Error::InvalidLevel);
+    }
+}
+
+#[test]
+fn test_level_must_be_unlocked() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    let proof2 = proof(&client, 1, &player1, 2, 5_000);
+    let result = client.try_submit_score(&1, &player1, &2, &5_000, &nonce(&env), &proof2);
+    assert_cubeathon_error(&result, Error::LevelNotUnlocked);
+
+    let proof1 = proof(&client, 1, &player1, 1, 4_000);
+    client.submit_score(&1, &player1, &1, &4_000, &nonce(&env), &proof1);
+    assert!(client.submit_score(&1, &player1, &2, &5_000, &nonce(&env), &proof2));
+    assert_eq!(client.get_game(&1).unwrap().p1_progress.levels.len(), 2);
+}
+
+#[test]
+fn test_proof_for_other_level_rejected() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    clear_levels(&client, 1, &player1, [4_000, 4_000, 4_000], true);
+
+    // A level-1 run cannot be claimed as a level-3 run.
+    let proof = proof(&client, 1, &player1, 1, 3_000);
+    assert!(client.try_submit_score(&1, &player1, &3, &3_000, &nonce(&env), &proof).is_err());
+}
+
+#[test]
+fn test_replayed_level_keeps_best_time() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    let nonce2 = BytesN::from_array(&env, &[2; 32]);
+    let slow = proof_for(&env, &client.get_journal_domain().unwrap(), &track(&client, 1, 1), &player1, 6_000, &nonce2);
+    client.submit_score(&1, &player1, &1, &5_000, &nonce(&env), &proof(&client, 1, &player1, 1, 5_000));
+    client.submit_score(&1, &player1, &1, &6_000, &nonce2, &slow);
+    assert_eq!(client.get_game(&1).unwrap().p1_progress.total_time_ms(), 5_000);
+
+    let nonce3 = BytesN::from_array(&env, &[3; 32]);
+    let fast = proof_for(&env, &client.get_journal_domain().unwrap(), &track(&client, 1, 1), &player1, 4_000, &nonce3);
+    client.submit_score(&1, &player1, &1, &4_000, &nonce3, &fast);
+    assert_eq!(client.get_game(&1).unwrap().p1_progress.total_time_ms(), 4_000);
+}
+
+#[test]
+fn test_lowest_total_time_wins() {
+    let Setup { client, player1, player2, .. } = setup_with(1, ProofPolicy::Dev);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    // Player 1 is faster on two levels but slower overall.
+    clear_levels(&client, 1, &player1, [3_000, 3_000, 9_000], true);
+    clear_levels(&client, 1, &player2, [4_000, 4_000, 5_000], true);
+
+    assert_eq!(client.end_session(&1), SessionOutcome::Winner(player2.clone()));
+    let board = client.get_leaderboard(&GameMode::Race);
+    assert_eq!(board.get_unchecked(0).player, player2);
+    assert_eq!(board.get_unchecked(0).time_ms, 13_000);
+}
+
+#[test]
+fn test_more_levels_cleared_wins() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    clear_levels(&client, 1, &player1, [9_000, 9_000, 9_000], true);
+    let proof = proof(&client, 1, &player2, 1, 1_000);
+    client.submit_score(&1, &player2, &1, &1_000, &nonce(&env), &proof);
+
+    pass_deadline(&env);
+    assert_eq!(client.end_session(&1), SessionOutcome::Winner(player1.clone()));
+}
+
+#[test]
+fn test_unfinished_winner_not_listed() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    let proof = proof(&client, 1, &player1, 1, 1_000);
+    client.submit_score(&1, &player1, &1, &1_000, &nonce(&env), &proof);
+
+    pass_deadline(&env);
+    assert_eq!(client.end_session(&1), SessionOutcome::Winner(player1.clone()));
+    assert!(client.get_leaderboard(&GameMode::Race).is_empty());
+}
+
+// ============================================================================
+// Game Mode Tests
+// ============================================================================
+
+fn advance_ledger(env: &Env) {
+    env.ledger().with_mut(|l| l.sequence_number += 1);
+}
+
+/// Scores on the `mode` leaderboard, best first.
+fn board_times(client: &CubeathonContractClient, mode: GameMode) -> Vec<u64> {
+    let mut times = Vec::new(&client.env);
+    for entry in client.get_leaderboard(&mode).iter() {
+        times.push_back(entry.time_ms);
+    }
+    times
+}
+
+/// Submit a proven run on `level`.
+fn run(client: &CubeathonContractClient, session_id: u32, player: &Address, level: u32, time_ms: u64) {
+    let proof = proof(client, session_id, player, level, time_ms);
+    client.submit_score(&session_id, player, &level, &time_ms, &nonce(&client.env), &proof);
+}
+
+#[test]
+fn test_race_tie_goes_to_earlier_finisher() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    clear_levels(&client, 1, &player2, [4_000, 4_000, 4_000], true);
+    advance_ledger(&env);
+    clear_levels(&client, 1, &player1, [3_000, 4_000, 5_000], true);
+
+    assert_eq!(client.end_session(&1), SessionOutcome::Winner(player2.clone()));
+}
+
+#[test]
+fn test_race_leaderboard_ascending() {
+    let Setup { env, client, player2, .. } = setup_with(1, ProofPolicy::Dev);
+    for (session, time) in [(1u32, 9_000u64), (2, 3_000), (3, 6_000)] {
+        let runner = Address::generate(&env);
+        client.start_game(&session, &runner, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+        clear_levels(&client, session, &runner, [time, time, time], true);
+        pass_deadline(&env);
+        client.end_session(&session);
+    }
+
+    assert_eq!(board_times(&client, GameMode::Race), vec![&env, 9_000, 18_000, 27_000]);
+    assert!(client.get_leaderboard(&GameMode::Survival).is_empty());
+}
+
+#[test]
+fn test_survival_has_a_single_level() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player1, 1, 5_000);
+
+    let proof = proof(&client, 1, &player1, 2, 5_000);
+    let result = client.try_submit_score(&1, &player1, &2, &5_000, &nonce(&env), &proof);
+    assert_cubeathon_error(&result, Error::InvalidLevel);
+}
+
+#[test]
+fn test_survival_keeps_longest_run() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player1, 1, 5_000);
+
+    let seed = track(&client, 1, 1);
+    for (time, tag) in [(3_000u64, 3u8), (8_000, 8)] {
+        let n = BytesN::from_array(&env, &[tag; 32]);
+        let p = proof_for(&env, &client.get_journal_domain().unwrap(), &seed, &player1, time, &n);
+        client.submit_score(&1, &player1, &1, &time, &n, &p);
+    }
+    assert_eq!(client.get_game(&1).unwrap().p1_progress.total_time_ms(), 8_000);
+}
+
+#[test]
+fn test_survival_longest_run_wins() {
+    let Setup { client, player1, player2, .. } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player1, 1, 5_000);
+    run(&client, 1, &player2, 1, 7_000);
+
+    assert_eq!(client.end_session(&1), SessionOutcome::Winner(player2.clone()));
+    let board = client.get_leaderboard(&GameMode::Survival);
+    assert_eq!(board.get_unchecked(0).time_ms, 7_000);
+    assert!(client.get_leaderboard(&GameMode::Race).is_empty());
+}
+
+#[test]
+fn test_survival_tie_goes_to_earlier_run() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player2, 1, 5_000);
+    advance_ledger(&env);
+    run(&client, 1, &player1, 1, 5_000);
+
+    assert_eq!(client.end_session(&1), SessionOutcome::Winner(player2.clone()));
+}
+
+#[test]
+fn test_survival_leaderboard_descending() {
+    let Setup { env, client, player2, .. } = setup_test();
+    for (session, time) in [(1u32, 4_000u64), (2, 9_000), (3, 6_000)] {
+        let runner = Address::generate(&env);
+        client.start_game(&session, &runner, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+        run(&client, session, &runner, 1, time);
+        pass_deadline(&env);
+        client.end_session(&session);
+    }
+
+    assert_eq!(board_times(&client, GameMode::Survival), vec![&env, 9_000, 6_000, 4_000]);
+}
+
+// ============================================================================
+// Draw and Tie-Break Tests
+// ============================================================================
+
+#[test]
+fn test_empty_session_is_a_draw() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+
+    pass_deadline(&env);
+    assert_eq!(client.end_session(&1), SessionOutcome::Draw);
+    let state = client.get_game(&1).unwrap();
+    assert!(state.draw);
+    assert_eq!(state.winner, None);
+    assert_eq!(client.get_draw_report(), DrawReport::Skip);
+    assert_eq!(hub_result(&client, 1), None);
+    assert_cubeathon_error(&client.try_end_session(&1), Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_draw_report_player1() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.set_draw_report(&DrawReport::Player1);
+    assert_eq!(client.get_draw_report(), DrawReport::Player1);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    pass_deadline(&env);
+    assert_eq!(client.end_session(&1), SessionOutcome::Draw);
+    assert_eq!(hub_result(&client, 1), Some(true));
+}
+
+#[test]
+#[should_panic]
+fn test_set_draw_report_requires_admin() {
+    let Setup { env, client, .. } = setup_test();
+    env.set_auths(&[]);
+
+    client.set_draw_report(&DrawReport::Player1);
+}
+
+#[test]
+fn test_same_ledger_tie_is_a_draw() {
+    let Setup { client, player1, player2, .. } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player1, 1, 5_000);
+    run(&client, 1, &player2, 1, 5_000);
+
+    assert_eq!(client.end_session(&1), SessionOutcome::Draw);
+    assert!(client.get_leaderboard(&GameMode::Survival).is_empty());
+}
+
+#[test]
+fn test_draw_tie_break_ignores_submission_order() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.set_tie_break(&TieBreak::Draw);
+    assert_eq!(client.get_tie_break(), TieBreak::Draw);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player1, 1, 5_000);
+    advance_ledger(&env);
+    run(&client, 1, &player2, 1, 5_000);
+
+    assert_eq!(client.end_session(&1), SessionOutcome::Draw);
+}
+
+#[test]
+fn test_replay_tie_break_restarts_session() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.set_tie_break(&TieBreak::Replay);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    let old_proof = proof(&client, 1, &player1, 1, 5_000);
+    run(&client, 1, &player1, 1, 5_000);
+    run(&client, 1, &player2, 1, 5_000);
+    let old_seed = client.get_game(&1).unwrap().seed;
+
+    assert_eq!(client.end_session(&1), SessionOutcome::Replay);
+    let state = client.get_game(&1).unwrap();
+    assert!(!state.is_over());
+    assert_ne!(state.seed, old_seed);
+    assert!(state.p1_progress.levels.is_empty() && state.p2_progress.levels.is_empty());
+    assert_eq!(hub_result(&client, 1), None);
+
+    // Runs from the tied round do not carry over to the new track.
+    assert!(client.try_submit_score(&1, &player1, &1, &5_000, &nonce(&env), &old_proof).is_err());
+
+    run(&client, 1, &player2, 1, 6_000);
+    pass_deadline(&env);
+    assert_eq!(client.end_session(&1), SessionOutcome::Winner(player2.clone()));
+    assert_eq!(hub_result(&client, 1), Some(false));
+}
+
+#[test]
+fn test_winner_reported_to_hub() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player1, 1, 7_000);
+
+    pass_deadline(&env);
+    assert_eq!(client.end_session(&1), SessionOutcome::Winner(player1.clone()));
+    assert_eq!(hub_result(&client, 1), Some(true));
+    assert!(client.get_game(&1).unwrap().forfeit);
+}
+
+#[test]
+fn test_settlement_event_reports_outcome() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    pass_deadline(&env);
+    client.end_session(&1);
+
+    let settled = SessionSettled { session_id: 1, outcome: SessionOutcome::Draw };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [settled.to_xdr(&env, &client.address)]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_set_tie_break_requires_admin() {
+    let Setup { env, client, .. } = setup_test();
+    env.set_auths(&[]);
+
+    client.set_tie_break(&TieBreak::Draw);
+}
+
+// ============================================================================
+// Session Status Tests
+// ============================================================================
+
+fn status(client: &CubeathonContractClient, session_id: u32) -> GameStatus {
+    client.get_game(&session_id).unwrap().status
+}
+
+#[test]
+fn test_ledger_seeded_session_lifecycle() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    assert_eq!(status(&client, 1), GameStatus::Pending);
+
+    run(&client, 1, &player1, 1, 5_000);
+    assert_eq!(status(&client, 1), GameStatus::Active);
+
+    pass_deadline(&env);
+    client.end_session(&1);
+    assert_eq!(status(&client, 1), GameStatus::Finalized);
+}
+
+#[test]
+fn test_commit_reveal_session_lifecycle() {
+    let setup = setup_test();
+    start_commit_reveal(&setup, 1);
+    let Setup { env, client, player1, player2 } = &setup;
+    assert_eq!(status(client, 1), GameStatus::AwaitingReveal);
+
+    client.reveal_seed(&1, player1, &entropy(env, 0xa1));
+    assert_eq!(status(client, 1), GameStatus::AwaitingReveal);
+    client.reveal_seed(&1, player2, &entropy(env, 0xb2));
+    assert_eq!(status(client, 1), GameStatus::Pending);
+
+    // Reveals and reveal timeouts are no longer allowed once play can begin.
+    advance_time(env, 3_601);
+    assert_cubeathon_error(&client.try_claim_reveal_timeout(&1), Error::SessionInProgress);
+}
+
+#[test]
+fn test_reveal_timeout_with_one_reveal_finalizes() {
+    let setup = setup_test();
+    start_commit_reveal(&setup, 1);
+    let Setup { env, client, player1, .. } = &setup;
+
+    client.reveal_seed(&1, player1, &entropy(env, 0xa1));
+    advance_time(env, 3_601);
+    client.claim_reveal_timeout(&1);
+    assert_eq!(status(client, 1), GameStatus::Finalized);
+}
+
+#[test]
+fn test_finalized_session_rejects_transitions() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player1, 1, 5_000);
+    pass_deadline(&env);
+    client.end_session(&1);
+
+    let proof = proof(&client, 1, &player1, 1, 6_000);
+    let result = client.try_submit_score(&1, &player1, &1, &6_000, &nonce(&env), &proof);
+    assert_cubeathon_error(&result, Error::GameAlreadyEnded);
+    assert_cubeathon_error(&client.try_end_session(&1), Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_expired_session_rejects_transitions() {
+    let setup = setup_test();
+    start_commit_reveal(&setup, 1);
+    let Setup { env, client, player1, .. } = &setup;
+    advance_time(env, 3_601);
+    client.claim_reveal_timeout(&1);
+
+    assert_cubeathon_error(&client.try_end_session(&1), Error::SessionExpired);
+    assert_cubeathon_error(&client.try_claim_reveal_timeout(&1), Error::SessionExpired);
+    let result = client.try_reveal_seed(&1, player1, &entropy(env, 0xa1));
+    assert_cubeathon_error(&result, Error::SessionExpired);
+}
+
+#[test]
+fn test_replay_returns_session_to_pending() {
+    let Setup { client, player1, player2, .. } = setup_test();
+    client.set_tie_break(&TieBreak::Replay);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player1, 1, 5_000);
+    run(&client, 1, &player2, 1, 5_000);
+
+    client.end_session(&1);
+    assert_eq!(status(&client, 1), GameStatus::Pending);
+}
+
+// ============================================================================
+// Session Deadline Tests
+// ============================================================================
+
+#[test]
+fn test_end_session_before_deadline_rejected() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    clear_levels(&client, 1, &player1, [5_000, 5_000, 5_000], true);
+    run(&client, 1, &player2, 1, 4_000);
+
+    // Player 2 is still playing: settling now would hand player 1 the win.
+    advance_time(&env, 86_400);
+    assert_cubeathon_error(&client.try_end_session(&1), Error::DeadlineNotReached);
+    assert_eq!(status(&client, 1), GameStatus::Active);
+}
+
+#[test]
+fn test_end_session_once_both_finished() {
+    let Setup { client, player1, player2, .. } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player1, 1, 5_000);
+    run(&client, 1, &player2, 1, 6_000);
+
+    assert_eq!(client.end_session(&1), SessionOutcome::Winner(player2.clone()));
+}
+
+#[test]
+fn test_end_session_after_deadline() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player1, 1, 5_000);
+
+    advance_time(&env, 86_401);
+    assert_eq!(client.end_session(&1), SessionOutcome::Winner(player1.clone()));
+}
+
+#[test]
+fn test_ledger_deadline() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.set_session_deadline(&SessionDeadline::Ledgers(10));
+    assert_eq!(client.get_session_deadline(), SessionDeadline::Ledgers(10));
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+
+    env.ledger().with_mut(|l| l.sequence_number += 10);
+    assert_cubeathon_error(&client.try_end_session(&1), Error::DeadlineNotReached);
+    advance_ledger(&env);
+    assert_eq!(client.end_session(&1), SessionOutcome::Draw);
+}
+
+#[test]
+fn test_running_session_keeps_its_deadline() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    client.set_session_deadline(&SessionDeadline::Seconds(60));
+
+    advance_time(&env, 61);
+    assert_cubeathon_error(&client.try_end_session(&1), Error::DeadlineNotReached);
+}
+
+#[test]
+fn test_replay_restarts_deadline() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.set_tie_break(&TieBreak::Replay);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    advance_time(&env, 80_000);
+    run(&client, 1, &player1, 1, 5_000);
+    run(&client, 1, &player2, 1, 5_000);
+    client.end_session(&1);
+
+    advance_time(&env, 10_000);
+    assert_cubeathon_error(&client.try_end_session(&1), Error::DeadlineNotReached);
+}
+
+#[test]
+#[should_panic]
+fn test_set_session_deadline_requires_admin() {
+    let Setup { env, client, .. } = setup_test();
+    env.set_auths(&[]);
+
+    client.set_session_deadline(&SessionDeadline::Seconds(60));
+}
+
+// ============================================================================
+// Forfeit Tests
+// ============================================================================
+
+#[test]
+fn test_forfeit_against_no_show() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player2, 1, 5_000);
+    pass_deadline(&env);
+
+    client.claim_forfeit(&1, &player2);
+    let forfeited = SessionForfeited { session_id: 1, winner: player2.clone() };
+    let settled = SessionSettled { session_id: 1, outcome: SessionOutcome::Winner(player2.clone()) };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [forfeited.to_xdr(&env, &client.address), settled.to_xdr(&env, &client.address)]
+    );
+
+    let state = client.get_game(&1).unwrap();
+    assert!(state.forfeit);
+    assert_eq!(state.winner, Some(player2.clone()));
+    assert_eq!(state.status, GameStatus::Finalized);
+    assert_eq!(hub_result(&client, 1), Some(false));
+    assert_cubeathon_error(&client.try_claim_forfeit(&1, &player2), Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_end_session_against_no_show_is_a_forfeit() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player2, 1, 5_000);
+    pass_deadline(&env);
+
+    // Anyone can settle, and it ends exactly like `claim_forfeit`.
+    assert_eq!(client.end_session(&1), SessionOutcome::Winner(player2.clone()));
+    let forfeited = SessionForfeited { session_id: 1, winner: player2.clone() };
+    let settled = SessionSettled { session_id: 1, outcome: SessionOutcome::Winner(player2.clone()) };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [forfeited.to_xdr(&env, &client.address), settled.to_xdr(&env, &client.address)]
+    );
+    let state = client.get_game(&1).unwrap();
+    assert!(state.forfeit);
+    assert_eq!(state.winner, Some(player2.clone()));
+    assert_eq!(hub_result(&client, 1), Some(false));
+}
+
+#[test]
+fn test_end_session_against_no_show_requires_proven_run() {
+    let Setup { env, client, player1, player2 } = setup_with(1, ProofPolicy::Optional);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    clear_levels(&client, 1, &player1, [4_000, 5_000, 6_000], false);
+    pass_deadline(&env);
+
+    assert_cubeathon_error(&client.try_end_session(&1), Error::ProofRequired);
+    assert!(!client.get_game(&1).unwrap().is_over());
+    assert_eq!(hub_result(&client, 1), None);
+}
+
+#[test]
+fn test_forfeit_before_deadline_rejected() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player1, 1, 5_000);
+
+    advance_time(&env, 86_400);
+    assert_cubeathon_error(&client.try_claim_forfeit(&1, &player1), Error::DeadlineNotReached);
+}
+
+#[test]
+fn test_forfeit_requires_own_score() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    pass_deadline(&env);
+
+    assert_cubeathon_error(&client.try_claim_forfeit(&1, &player1), Error::NoScoreSubmitted);
+}
+
+#[test]
+fn test_forfeit_requires_proven_run() {
+    let Setup { env, client, player1, player2 } = setup_with(1, ProofPolicy::Optional);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    clear_levels(&client, 1, &player1, [4_000, 5_000, 6_000], false);
+    pass_deadline(&env);
+
+    assert_cubeathon_error(&client.try_claim_forfeit(&1, &player1), Error::ProofRequired);
+    assert!(!client.get_game(&1).unwrap().is_over());
+}
+
+#[test]
+fn test_dev_policy_forfeit_accepts_unproven_run() {
+    let Setup { env, client, player1, player2 } = setup_with(1, ProofPolicy::Dev);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    clear_levels(&client, 1, &player1, [4_000, 5_000, 6_000], false);
+    pass_deadline(&env);
+
+    client.claim_forfeit(&1, &player1);
+    assert!(client.get_game(&1).unwrap().forfeit);
+}
+
+#[test]
+fn test_forfeit_rejected_once_opponent_played() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    clear_levels(&client, 1, &player1, [5_000, 5_000, 5_000], true);
+    run(&client, 1, &player2, 1, 9_000);
+    pass_deadline(&env);
+
+    // Both players took part, so the result is decided on standings instead.
+    assert_cubeathon_error(&client.try_claim_forfeit(&1, &player1), Error::OpponentHasPlayed);
+    assert_eq!(client.end_session(&1), SessionOutcome::Winner(player1.clone()));
+    assert!(!client.get_game(&1).unwrap().forfeit);
+}
+
+#[test]
+fn test_forfeit_claimant_must_be_player() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player1, 1, 5_000);
+    pass_deadline(&env);
+
+    let outsider = Address::generate(&env);
+    assert_cubeathon_error(&client.try_claim_forfeit(&1, &outsider), Error::NotPlayer);
+}
+
+#[test]
+fn test_forfeit_win_lists_finished_run() {
+    let Setup { env, client, player1, player2 } = setup_with(1, ProofPolicy::Dev);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    clear_levels(&client, 1, &player1, [4_000, 5_000, 6_000], true);
+    pass_deadline(&env);
+
+    client.claim_forfeit(&1, &player1);
+    assert_eq!(board_times(&client, GameMode::Race), vec![&env, 15_000]);
+}
+
+// ============================================================================
+// Cancellation Tests
+// ============================================================================
+
+fn admin(client: &CubeathonContractClient) -> Address {
+    client.env.as_contract(&client.address, || {
+        client.env.storage().instance().get(&DataKey::Admin).unwrap()
+    })
+}
+
+#[test]
+fn test_players_cancel_session() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player1, 1, 5_000);
+
+    client.cancel_session(&1, &CancelledBy::Players);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 2);
+    assert_eq!(auths[0].0, player1);
+    assert_eq!(auths[1].0, player2);
+
+    let cancelled = SessionCancelled { session_id: 1, by: CancelledBy::Players };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [cancelled.to_xdr(&env, &client.address)]
+    );
+    let state = client.get_game(&1).unwrap();
+    assert_eq!(state.status, GameStatus::Cancelled);
+    assert!(state.is_over());
+    assert_eq!(state.winner, None);
+    assert_eq!(hub_result(&client, 1), None);
+}
+
+#[test]
+fn test_admin_cancels_session() {
+    let setup = setup_test();
+    start_commit_reveal(&setup, 1);
+    let Setup { env, client, .. } = &setup;
+
+    client.cancel_session(&1, &CancelledBy::Admin);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, admin(client));
+    assert_eq!(status(client, 1), GameStatus::Cancelled);
+}
+
+#[test]
+#[should_panic]
+fn test_cancel_requires_both_players() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    env.set_auths(&[]);
+
+    client.cancel_session(&1, &CancelledBy::Players);
+}
+
+#[test]
+fn test_cancel_never_reports_a_winner() {
+    let Setup { client, player1, player2, .. } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    client.cancel_session(&1, &CancelledBy::Admin);
+    assert_eq!(hub_result(&client, 1), None);
+
+    // Not even when draws are reported as a player 1 win.
+    client.set_draw_report(&DrawReport::Player1);
+    client.start_game(&2, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    client.cancel_session(&2, &CancelledBy::Admin);
+    assert_eq!(hub_result(&client, 2), None);
+}
+
+#[test]
+fn test_cancelled_session_rejects_transitions() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    client.cancel_session(&1, &CancelledBy::Admin);
+    pass_deadline(&env);
+
+    let proof = proof(&client, 1, &player1, 1, 5_000);
+    let result = client.try_submit_score(&1, &player1, &1, &5_000, &nonce(&env), &proof);
+    assert_cubeathon_error(&result, Error::SessionCancelled);
+    assert_cubeathon_error(&client.try_end_session(&1), Error::SessionCancelled);
+    assert_cubeathon_error(&client.try_cancel_session(&1, &CancelledBy::Admin), Error::SessionCancelled);
+}
+
+#[test]
+fn test_finalized_session_cannot_be_cancelled() {
+    let Setup { client, player1, player2, .. } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player1, 1, 5_000);
+    run(&client, 1, &player2, 1, 6_000);
+    client.end_session(&1);
+
+    let result = client.try_cancel_session(&1, &CancelledBy::Players);
+    assert_cubeathon_error(&result, Error::GameAlreadyEnded);
+    assert_eq!(client.get_game(&1).unwrap().winner, Some(player2.clone()));
+}
+
+// ============================================================================
+// Session Id Tests
+// ============================================================================
+
+#[test]
+fn test_duplicate_session_id_rejected() {
+    let Setup { client, player1, player2, .. } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player1, 1, 5_000);
+
+    let result = client.try_start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    assert_cubeathon_error(&result, Error::SessionAlreadyExists);
+    let state = client.get_game(&1).unwrap();
+    assert_eq!(state.mode, GameMode::Survival);
+    assert_eq!(state.p1_progress.total_time_ms(), 5_000);
+}
+
+#[test]
+fn test_finished_session_id_not_reused() {
+    let Setup { client, player1, player2, .. } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    client.cancel_session(&1, &CancelledBy::Admin);
+
+    let result = client.try_start_game(&1, &player2, &player1, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    assert_cubeathon_error(&result, Error::SessionAlreadyExists);
+}
+
+#[test]
+fn test_create_session_allocates_sequential_ids() {
+    let Setup { client, player1, player2, .. } = setup_test();
+    let first = client.create_session(&player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    let second = client.create_session(&player2, &player1, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+
+    assert_eq!((first, second), (1 << 31, (1 << 31) + 1));
+    assert_eq!(client.get_game(&first).unwrap().mode, GameMode::Race);
+    assert_eq!(client.get_game(&second).unwrap().player1, player2);
+}
+
+#[test]
+fn test_start_game_rejects_allocator_ids() {
+    let Setup { client, player1, player2, .. } = setup_test();
+    for id in [1u32 << 31, u32::MAX] {
+        assert_cubeathon_error(
+            &client.try_start_game(&id, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None),
+            Error::SessionIdReserved,
+        );
+    }
+
+    client.start_game(&((1 << 31) - 1), &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    let id = client.create_session(&player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    assert_eq!(id, 1 << 31);
+}
+
+#[test]
+fn test_create_session_wraps_after_last_id() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(&DataKey::NextSessionId, &u32::MAX);
+    });
+
+    let last = client.create_session(&player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    let wrapped = client.create_session(&player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    assert_eq!((last, wrapped), (u32::MAX, 1 << 31));
+}
+
+#[test]
+fn test_create_session_reports_allocated_id_to_hub() {
+    let Setup { client, player1, player2, .. } = setup_test();
+    let id = client.create_session(&player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, id, &player1, 1, 5_000);
+    run(&client, id, &player2, 1, 4_000);
+
+    client.end_session(&id);
+    assert_eq!(hub_result(&client, id), Some(true));
+}
+
+// ============================================================================
+// Authorization Tests
+// ============================================================================
+
+/// Auth entry for `player` signing `fn_name` over `args`.
+fn signed(
+    client: &CubeathonContractClient,
+    player: &Address,
+    fn_name: &str,
+    args: Vec<Val>,
+) -> (Address, AuthorizedInvocation) {
+    let invocation = AuthorizedInvocation {
+        function: AuthorizedFunction::Contract((
+            client.address.clone(),
+            Symbol::new(&client.env, fn_name),
+            args,
+        )),
+        sub_invocations: Default::default(),
+    };
+    (player.clone(), invocation)
+}
+
+#[test]
+fn test_start_game_requires_both_players() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&7, &player1, &player2, &100, &200, &GameMode::Race, &Difficulty::Normal, &None);
+
+    assert_eq!(
+        env.auths(),
+        [
+            signed(&client, &player1, "start_game", (7u32, player2.clone(), 100i128, GameMode::Race, Difficulty::Normal, None::<BytesN<32>>).into_val(&env)),
+            signed(&client, &player2, "start_game", (7u32, player1.clone(), 200i128, GameMode::Race, Difficulty::Normal, None::<BytesN<32>>).into_val(&env)),
+        ]
+    );
+}
+
+#[test]
+fn test_create_session_auth_omits_session_id() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.create_session(&player1, &player2, &100, &200, &GameMode::Race, &Difficulty::Normal, &None);
+
+    assert_eq!(
+        env.auths(),
+        [
+            signed(&client, &player1, "create_session", (player2.clone(), 100i128, GameMode::Race, Difficulty::Normal, None::<BytesN<32>>).into_val(&env)),
+            signed(&client, &player2, "create_session", (player1.clone(), 200i128, GameMode::Race, Difficulty::Normal, None::<BytesN<32>>).into_val(&env)),
+        ]
+    );
+}
+
+/// Mocked `start_game` invocation signed over
+/// `(session_id, opponent, points, mode, difficulty, own_commit)`.
+fn start_invoke<'a>(
+    client: &'a CubeathonContractClient,
+    session_id: u32,
+    opponent: &Address,
+    points: i128,
+    mode: GameMode,
+    difficulty: Difficulty,
+    own_commit: Option<BytesN<32>>,
+) -> MockAuthInvoke<'a> {
+    MockAuthInvoke {
+        contract: &client.address,
+        fn_name: "start_game",
+        args: (session_id, opponent.clone(), points, mode, difficulty, own_commit).into_val(&client.env),
+        sub_invokes: &[],
+    }
+}
+
+#[test]
+fn test_start_game_with_exact_signatures() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    let p1_invoke = start_invoke(&client, 1, &player2, 100, GameMode::Race, Difficulty::Normal, None);
+    let p2_invoke = start_invoke(&client, 1, &player1, 200, GameMode::Race, Difficulty::Normal, None);
+    env.mock_auths(&[
+        MockAuth { address: &player1, invoke: &p1_invoke },
+        MockAuth { address: &player2, invoke: &p2_invoke },
+    ]);
+
+    client.start_game(&1, &player1, &player2, &100, &200, &GameMode::Race, &Difficulty::Normal, &None);
+    assert_eq!(client.get_game(&1).unwrap().p1_points, 100);
+}
+
+#[test]
+fn test_start_game_without_player1_signature_rejected() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    let p2_invoke = start_invoke(&client, 1, &player1, 200, GameMode::Race, Difficulty::Normal, None);
+    env.mock_auths(&[MockAuth { address: &player2, invoke: &p2_invoke }]);
+
+    let result = client.try_start_game(&1, &player1, &player2, &100, &200, &GameMode::Race, &Difficulty::Normal, &None);
+    assert!(result.is_err());
+    assert!(client.get_game(&1).is_none());
+}
+
+#[test]
+fn test_player1_signature_bound_to_points() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    // Player 1 agreed to stake 100; player 2 submits 500 on their behalf.
+    let p1_invoke = start_invoke(&client, 1, &player2, 100, GameMode::Race, Difficulty::Normal, None);
+    let p2_invoke = start_invoke(&client, 1, &player1, 200, GameMode::Race, Difficulty::Normal, None);
+    env.mock_auths(&[
+        MockAuth { address: &player1, invoke: &p1_invoke },
+        MockAuth { address: &player2, invoke: &p2_invoke },
+    ]);
+
+    let result = client.try_start_game(&1, &player1, &player2, &500, &200, &GameMode::Race, &Difficulty::Normal, &None);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_player1_signature_bound_to_session() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    let p1_invoke = start_invoke(&client, 1, &player2, 100, GameMode::Race, Difficulty::Normal, None);
+    let p2_invoke = start_invoke(&client, 2, &player1, 200, GameMode::Race, Difficulty::Normal, None);
+    env.mock_auths(&[
+        MockAuth { address: &player1, invoke: &p1_invoke },
+        MockAuth { address: &player2, invoke: &p2_invoke },
+    ]);
+
+    let result = client.try_start_game(&2, &player1, &player2, &100, &200, &GameMode::Race, &Difficulty::Normal, &None);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_player1_signature_bound_to_mode() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    // Player 1 agreed to a Race on Normal; player 2 submits Survival.
+    let p1_invoke = start_invoke(&client, 1, &player2, 100, GameMode::Race, Difficulty::Normal, None);
+    let p2_invoke = start_invoke(&client, 1, &player1, 200, GameMode::Survival, Difficulty::Normal, None);
+    env.mock_auths(&[
+        MockAuth { address: &player1, invoke: &p1_invoke },
+        MockAuth { address: &player2, invoke: &p2_invoke },
+    ]);
+
+    let result = client.try_start_game(&1, &player1, &player2, &100, &200, &GameMode::Survival, &Difficulty::Normal, &None);
+    assert!(result.is_err());
+    assert!(client.get_game(&1).is_none());
+}
+
+#[test]
+fn test_player1_signature_bound_to_difficulty() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    let p1_invoke = start_invoke(&client, 1, &player2, 100, GameMode::Race, Difficulty::Easy, None);
+    let p2_invoke = start_invoke(&client, 1, &player1, 200, GameMode::Race, Difficulty::Hard, None);
+    env.mock_auths(&[
+        MockAuth { address: &player1, invoke: &p1_invoke },
+        MockAuth { address: &player2, invoke: &p2_invoke },
+    ]);
+
+    let result = client.try_start_game(&1, &player1, &player2, &100, &200, &GameMode::Race, &Difficulty::Hard, &None);
+    assert!(result.is_err());
+    assert!(client.get_game(&1).is_none());
+}
+
+#[test]
+fn test_commit_reveal_signatures_cover_own_commitment() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    let p1_commit = commit(&env, &entropy(&env, 0xa1));
+    let p2_commit = commit(&env, &entropy(&env, 0xb2));
+    let p1_invoke = start_invoke(&client, 1, &player2, 100, GameMode::Race, Difficulty::Normal, Some(p1_commit.clone()));
+    let p2_invoke = start_invoke(&client, 1, &player1, 200, GameMode::Race, Difficulty::Normal, Some(p2_commit.clone()));
+    env.mock_auths(&[
+        MockAuth { address: &player1, invoke: &p1_invoke },
+        MockAuth { address: &player2, invoke: &p2_invoke },
+    ]);
+
+    let commitments = SeedCommitments { p1_commit, p2_commit };
+    client.start_game(&1, &player1, &player2, &100, &200, &GameMode::Race, &Difficulty::Normal, &Some(commitments));
+    assert_eq!(client.get_game(&1).unwrap().status, GameStatus::AwaitingReveal);
+}
+
+#[test]
+fn test_player1_signature_bound_to_commitment() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    let p1_commit = commit(&env, &entropy(&env, 0xa1));
+    let p2_commit = commit(&env, &entropy(&env, 0xb2));
+    let p1_invoke = start_invoke(&client, 1, &player2, 100, GameMode::Race, Difficulty::Normal, Some(p1_commit));
+    let p2_invoke = start_invoke(&client, 1, &player1, 200, GameMode::Race, Difficulty::Normal, Some(p2_commit.clone()));
+    env.mock_auths(&[
+        MockAuth { address: &player1, invoke: &p1_invoke },
+        MockAuth { address: &player2, invoke: &p2_invoke },
+    ]);
+
+    // Player 2 swaps in a commitment player 1 cannot open, hoping to win
+    // through `claim_reveal_timeout`.
+    let commitments = SeedCommitments { p1_commit: commit(&env, &entropy(&env, 0xee)), p2_commit };
+    let result = client.try_start_game(&1, &player1, &player2, &100, &200, &GameMode::Race, &Difficulty::Normal, &Some(commitments));
+    assert!(result.is_err());
+    assert!(client.get_game(&1).is_none());
+}
+
+// ============================================================================
+// Leaderboard Storage Tests
+// ============================================================================
+
+/// Insert `count` entries straight into the `mode` board, which holds up to
+/// `LEADERBOARD_MAX` entries as in the contract; entry `i` has session id
+/// `i` and score `score(i)`. Returns the players, in insertion order.
+fn fill_board(client: &CubeathonContractClient, mode: GameMode, count: u32, score: impl Fn(u32) -> u64) -> Vec<Address> {
+    fill_capped(client, mode, count, LEADERBOARD_MAX, score)
+}
+
+/// `fill_board` into a board holding at most `max_len` entries.
+fn fill_capped(
+    client: &CubeathonContractClient,
+    mode: GameMode,
+    count: u32,
+    max_len: u32,
+    score: impl Fn(u32) -> u64,
+) -> Vec<Address> {
+    let env = &client.env;
+    let board = current_board(client, mode);
+    let mut players = Vec::new(env);
+    for i in 0..count {
+        let entry = LeaderboardEntry {
+            player: Address::generate(env),
+            time_ms: score(i),
+            session_id: i,
+            timestamp: 0,
+        };
+        players.push_back(entry.player.clone());
+        env.as_contract(&client.address, || leaderboard::insert(env, board, entry, max_len));
+    }
+    players
+}
+
+/// Lay out `count` entries on `board` straight into full buckets, far
+/// quicker than inserting them one by one. Scores must be in rank order.
+fn preload_board(client: &CubeathonContractClient, board: Board, count: u32, score: impl Fn(u32) -> u64) {
+    let env = &client.env;
+    let mut index = BoardIndex { buckets: Vec::new(env), len: count, next_id: 0 };
+    for start in (0..count).step_by(64) {
+        let mut bucket = Vec::new(env);
+        for i in start..count.min(start + 64) {
+            let player = Address::generate(env);
+            bucket.push_back(LeaderboardEntry { player, time_ms: score(i), session_id: i, timestamp: 0 });
+        }
+        let id = index.next_id;
+        index.buckets.push_back(BucketInfo { id, len: bucket.len(), last: bucket.last_unchecked().time_ms });
+        index.next_id += 1;
+        let key = DataKey::LeaderboardBucket(board.key(), id);
+        env.as_contract(&client.address, || env.storage().persistent().set(&key, &bucket));
+    }
+    let key = DataKey::Leaderboard(board.key());
+    env.as_contract(&client.address, || env.storage().persistent().set(&key, &index));
+}
+
+/// Bucket lengths of the `mode` board, in rank order.
+fn bucket_lens(client: &CubeathonContractClient, mode: GameMode) -> Vec<u32> {
+    let env = &client.env;
+    let key = DataKey::Leaderboard(current_board(client, mode).key());
+    let index: BoardIndex = env.as_contract(&client.address, || env.storage().persistent().get(&key).unwrap());
+    let mut lens = Vec::new(env);
+    for info in index.buckets.iter() {
+        lens.push_back(info.len);
+    }
+    lens
+}
+
+/// The combined `mode` board at `Normal` difficulty in the current season.
+fn current_board(client: &CubeathonContractClient, mode: GameMode) -> Board {
+    client.get_current_board(&mode, &Difficulty::Normal, &0)
+}
+
+/// Every entry of the `mode` board, read `limit` at a time.
+fn read_board(client: &CubeathonContractClient, mode: GameMode, limit: u32) -> Vec<LeaderboardEntry> {
+    let mut entries = Vec::new(&client.env);
+    let mut cursor = Some(0);
+    while let Some(at) = cursor {
+        let page = client.get_leaderboard_page(&current_board(client, mode), &at, &limit);
+        entries.append(&page.entries);
+        cursor = page.next_cursor;
+    }
+    entries
+}
+
+#[test]
+fn test_leaderboard_pages_are_ranked_across_buckets() {
+    let Setup { client, .. } = setup_test();
+    fill_board(&client, GameMode::Race, 300, |i| (i as u64 * 7_919) % 1_000);
+
+    let entries = read_board(&client, GameMode::Race, 37);
+    assert_eq!(entries.len(), 300);
+    for i in 1..entries.len() {
+        assert!(entries.get_unchecked(i - 1).time_ms <= entries.get_unchecked(i).time_ms);
+    }
+}
+
+#[test]
+fn test_leaderboard_ties_keep_insertion_order() {
+    let Setup { client, .. } = setup_test();
+    fill_board(&client, GameMode::Survival, 200, |_| 5_000);
+
+    let entries = read_board(&client, GameMode::Survival, 100);
+    for (i, entry) in entries.iter().enumerate() {
+        assert_eq!(entry.session_id, i as u32);
+    }
+}
+
+#[test]
+fn test_leaderboard_page_bounds() {
+    let Setup { client, .. } = setup_test();
+    fill_board(&client, GameMode::Race, 150, |i| i as u64);
+
+    let page = client.get_leaderboard_page(&current_board(&client, GameMode::Race), &0, &500);
+    assert_eq!(page.entries.len(), 100);
+    assert_eq!(page.next_cursor, Some(100));
+
+    let page = client.get_leaderboard_page(&current_board(&client, GameMode::Race), &140, &20);
+    assert_eq!(page.entries.len(), 10);
+    assert_eq!(page.entries.get_unchecked(0).time_ms, 140);
+    assert_eq!(page.next_cursor, None);
+
+    assert!(client.get_leaderboard_page(&current_board(&client, GameMode::Race), &150, &20).entries.is_empty());
+    assert_eq!(client.get_leaderboard(&GameMode::Race).len(), 50);
+}
+
+#[test]
+fn test_full_leaderboard_keeps_best_entries() {
+    let Setup { client, .. } = setup_test();
+    fill_capped(&client, GameMode::Race, 300, 300, |i| 1_000 + i as u64);
+    fill_capped(&client, GameMode::Race, 2, 300, |i| [500, 50_000][i as usize]);
+
+    let page = client.get_leaderboard_page(&current_board(&client, GameMode::Race), &290, &100);
+    assert_eq!(page.entries.len(), 10);
+    assert_eq!(page.entries.last_unchecked().time_ms, 1_000 + 298);
+    assert_eq!(client.get_leaderboard(&GameMode::Race).get_unchecked(0).time_ms, 500);
+}
+
+#[test]
+fn test_drained_buckets_are_merged() {
+    let Setup { env, client, .. } = setup_test();
+    let players = fill_board(&client, GameMode::Race, 256, |i| 1_000 + i as u64);
+    let board = current_board(&client, GameMode::Race);
+
+    // Move every other player to the top, draining the buckets they leave.
+    for (i, player) in players.iter().enumerate().step_by(2) {
+        let entry = LeaderboardEntry { player, time_ms: i as u64, session_id: 0, timestamp: 0 };
+        env.as_contract(&client.address, || leaderboard::insert(&env, board, entry, LEADERBOARD_MAX));
+    }
+
+    let lens = bucket_lens(&client, GameMode::Race);
+    assert!(lens.len() <= 2 * 256 / 64);
+    assert!(lens.iter().all(|len| (32..=64).contains(&len)));
+    assert_eq!(lens.iter().sum::<u32>(), 256);
+    let entries = read_board(&client, GameMode::Race, 100);
+    for i in 1..entries.len() {
+        assert!(entries.get_unchecked(i - 1).time_ms <= entries.get_unchecked(i).time_ms);
+    }
+}
+
+#[test]
+fn test_evictions_keep_buckets_half_full() {
+    let Setup { client, .. } = setup_test();
+    fill_capped(&client, GameMode::Survival, 200, 200, |i| 1_000 + i as u64);
+    // Each new top entry evicts from the last bucket.
+    fill_capped(&client, GameMode::Survival, 150, 200, |i| 10_000 + i as u64);
+
+    let lens = bucket_lens(&client, GameMode::Survival);
+    assert!(lens.iter().all(|len| (32..=64).contains(&len)));
+    assert_eq!(lens.iter().sum::<u32>(), 200);
+    assert_eq!(read_board(&client, GameMode::Survival, 100).last_unchecked().time_ms, 1_150);
+}
+
+#[test]
+fn test_leaderboard_holds_production_capacity() {
+    let Setup { client, player1, player2, .. } = setup_test();
+    preload_board(&client, current_board(&client, GameMode::Survival), LEADERBOARD_MAX, |i| 12_000 - i as u64);
+
+    // Too slow for a full board; a better run evicts the last entry.
+    win_survival(&client, 1, &player1, &player2, 1_000);
+    assert!(client.get_player_best(&current_board(&client, GameMode::Survival), &player1).is_none());
+    win_survival(&client, 2, &player2, &player1, 50_000);
+    assert_eq!(client.get_rank(&current_board(&client, GameMode::Survival), &player2), Some(1));
+
+    let last = client.get_leaderboard_page(&current_board(&client, GameMode::Survival), &(LEADERBOARD_MAX - 1), &10);
+    assert_eq!(last.entries.len(), 1);
+    assert_eq!(last.entries.get_unchecked(0).time_ms, 12_000 - (LEADERBOARD_MAX - 2) as u64);
+    assert_eq!(last.next_cursor, None);
+}
+
+/// Per-transaction ledger write limits of the network.
+const TX_WRITE_ENTRIES: u32 = 50;
+const TX_WRITE_BYTES:   u32 = 132_096;
+
+/// Assert that the last invocation stayed within the write limits.
+fn assert_fits_transaction(env: &Env) {
+    let resources = env.cost_estimate().resources();
+    assert!(resources.write_entries <= TX_WRITE_ENTRIES, "{} entries written", resources.write_entries);
+    assert!(resources.write_bytes <= TX_WRITE_BYTES, "{} bytes written", resources.write_bytes);
+}
+
+#[test]
+fn test_board_writes_at_capacity_fit_transaction_limits() {
+    let Setup { env, client, player1, player2 } = setup_with(1, ProofPolicy::Dev);
+    // Every board is full, so each insert evicts and splits the top bucket.
+    for level in 0..=3 {
+        let board = client.get_current_board(&GameMode::Race, &Difficulty::Normal, &level);
+        preload_board(&client, board, LEADERBOARD_MAX, |i| 20_000 + i as u64);
+    }
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    clear_levels(&client, 1, &player2, [9_000, 9_000, 9_000], true);
+
+    // A level run goes on its level board, the settlement on the combined one.
+    for level in 1..=3 {
+        run(&client, 1, &player1, level, 1_000);
+        assert_fits_transaction(&env);
+    }
+    client.end_session(&1);
+    assert_fits_transaction(&env);
+    assert_eq!(client.get_rank(&current_board(&client, GameMode::Race), &player1), Some(1));
+}
+
+// ============================================================================
+// Personal Best Tests
+// ============================================================================
+
+/// Win a Survival session with a proven `time_ms` run against a no-show.
+fn win_survival(client: &CubeathonContractClient, session_id: u32, player: &Address, opponent: &Address, time_ms: u64) {
+    client.start_game(&session_id, player, opponent, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(client, session_id, player, 1, time_ms);
+    pass_deadline(&client.env);
+    client.end_session(&session_id);
+}
+
+#[test]
+fn test_player_best_improves_in_place() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    let rival = Address::generate(&env);
+    win_survival(&client, 1, &player1, &player2, 4_000);
+    win_survival(&client, 2, &rival, &player2, 6_000);
+    win_survival(&client, 3, &player1, &player2, 9_000);
+
+    assert_eq!(board_times(&client, GameMode::Survival), vec![&env, 9_000, 6_000]);
+    let best = client.get_player_best(&current_board(&client, GameMode::Survival), &player1).unwrap();
+    assert_eq!((best.time_ms, best.session_id), (9_000, 3));
+    assert_eq!(client.get_leaderboard(&GameMode::Survival).get_unchecked(0).player, player1);
+}
+
+#[test]
+fn test_player_best_kept_when_not_improved() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    win_survival(&client, 1, &player1, &player2, 8_000);
+    win_survival(&client, 2, &player1, &player2, 5_000);
+    win_survival(&client, 3, &player1, &player2, 8_000);
+
+    assert_eq!(board_times(&client, GameMode::Survival), vec![&env, 8_000]);
+    assert_eq!(client.get_player_best(&current_board(&client, GameMode::Survival), &player1).unwrap().session_id, 1);
+}
+
+#[test]
+fn test_player_best_is_per_mode() {
+    let Setup { client, player1, player2, .. } = setup_test();
+    win_survival(&client, 1, &player1, &player2, 8_000);
+
+    assert!(client.get_player_best(&current_board(&client, GameMode::Race), &player1).is_none());
+    assert!(client.get_player_best(&current_board(&client, GameMode::Survival), &player2).is_none());
+}
+
+#[test]
+fn test_evicted_player_loses_best() {
+    let Setup { client, player1, player2, .. } = setup_test();
+    win_survival(&client, 1, &player1, &player2, 1_000);
+    // Filling the board to a 300 entry capacity pushes player 1 off it.
+    fill_capped(&client, GameMode::Survival, 300, 300, |i| 2_000 + i as u64);
+
+    assert!(client.get_player_best(&current_board(&client, GameMode::Survival), &player1).is_none());
+    let page = client.get_leaderboard_page(&current_board(&client, GameMode::Survival), &250, &100);
+    assert_eq!(page.entries.last_unchecked().time_ms, 2_000);
+
+    // Back on the board with a good enough run.
+    win_survival(&client, 2, &player1, &player2, 3_000);
+    assert_eq!(client.get_player_best(&current_board(&client, GameMode::Survival), &player1).unwrap().time_ms, 3_000);
+    assert_eq!(client.get_leaderboard_page(&current_board(&client, GameMode::Survival), &0, &300).next_cursor, Some(100));
+}
+
+#[test]
+fn test_improved_entry_moves_across_buckets() {
+    let Setup { env, client, player1, .. } = setup_test();
+    fill_board(&client, GameMode::Race, 200, |i| 1_000 + i as u64);
+    let entry = |time_ms| LeaderboardEntry { player: player1.clone(), time_ms, session_id: 0, timestamp: 0 };
+    let board = current_board(&client, GameMode::Race);
+    env.as_contract(&client.address, || leaderboard::insert(&env, board, entry(5_000), LEADERBOARD_MAX));
+    env.as_contract(&client.address, || leaderboard::insert(&env, board, entry(1_100), LEADERBOARD_MAX));
+
+    // Listed once, behind the earlier entry with the same time.
+    let entries = read_board(&client, GameMode::Race, 100);
+    assert_eq!(entries.len(), 201);
+    assert_eq!(entries.get_unchecked(101).player, player1);
+    assert_eq!(entries.iter().filter(|e| e.player == player1).count(), 1);
+}
+
+// ============================================================================
+// Rank Lookup Tests
+// ============================================================================
+
+/// Session ids of `entries`, in order.
+fn session_ids(env: &Env, entries: Vec<LeaderboardEntry>) -> Vec<u32> {
+    let mut ids = Vec::new(env);
+    for entry in entries.iter() {
+        ids.push_back(entry.session_id);
+    }
+    ids
+}
+
+/// Player holding the entry at rank offset `offset`.
+fn player_at(client: &CubeathonContractClient, mode: GameMode, offset: u32) -> Address {
+    client.get_leaderboard_page(&current_board(client, mode), &offset, &1).entries.get_unchecked(0).player
+}
+
+#[test]
+fn test_rank_of_listed_player() {
+    let Setup { client, player1, player2, .. } = setup_test();
+    fill_board(&client, GameMode::Race, 150, |i| 1_000 + i as u64);
+
+    assert_eq!(client.get_rank(&current_board(&client, GameMode::Race), &player_at(&client, GameMode::Race, 0)), Some(1));
+    assert_eq!(client.get_rank(&current_board(&client, GameMode::Race), &player_at(&client, GameMode::Race, 36)), Some(37));
+    assert_eq!(client.get_rank(&current_board(&client, GameMode::Race), &player_at(&client, GameMode::Race, 149)), Some(150));
+
+    win_survival(&client, 1, &player1, &player2, 5_000);
+    assert_eq!(client.get_rank(&current_board(&client, GameMode::Survival), &player1), Some(1));
+    assert_eq!(client.get_rank(&current_board(&client, GameMode::Race), &player1), None);
+}
+
+#[test]
+fn test_rank_among_equal_times() {
+    let Setup { client, .. } = setup_test();
+    fill_board(&client, GameMode::Survival, 150, |_| 5_000);
+
+    let player = player_at(&client, GameMode::Survival, 120);
+    assert_eq!(client.get_rank(&current_board(&client, GameMode::Survival), &player), Some(121));
+}
+
+#[test]
+fn test_entries_around_player() {
+    let Setup { env, client, .. } = setup_test();
+    fill_board(&client, GameMode::Race, 150, |i| 1_000 + i as u64);
+
+    let player = player_at(&client, GameMode::Race, 36);
+    let around = client.get_entries_around(&current_board(&client, GameMode::Race), &player, &5);
+    assert_eq!(session_ids(&env, around), vec![&env, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41]);
+}
+
+#[test]
+fn test_entries_around_board_edges() {
+    let Setup { env, client, player1, .. } = setup_test();
+    fill_board(&client, GameMode::Race, 10, |i| 1_000 + i as u64);
+
+    let top = player_at(&client, GameMode::Race, 0);
+    assert_eq!(session_ids(&env, client.get_entries_around(&current_board(&client, GameMode::Race), &top, &2)), vec![&env, 0, 1, 2]);
+    let bottom = player_at(&client, GameMode::Race, 9);
+    assert_eq!(session_ids(&env, client.get_entries_around(&current_board(&client, GameMode::Race), &bottom, &2)), vec![&env, 7, 8, 9]);
+
+    assert_eq!(client.get_entries_around(&current_board(&client, GameMode::Race), &top, &1_000).len(), 10);
+    assert!(client.get_entries_around(&current_board(&client, GameMode::Race), &player1, &2).is_empty());
+}
+
+#[test]
+fn test_entries_around_radius_capped() {
+    let Setup { client, .. } = setup_test();
+    fill_board(&client, GameMode::Race, 150, |i| 1_000 + i as u64);
+
+    let player = player_at(&client, GameMode::Race, 75);
+    let around = client.get_entries_around(&current_board(&client, GameMode::Race), &player, &u32::MAX);
+    assert_eq!(around.len(), 99);
+    assert_eq!(around.get_unchecked(49).player, player);
+}
+
+// ============================================================================
+// Season Tests
+// ============================================================================
+
+const DAY: u64 = 86_400;
+
+#[test]
+fn test_new_season_archives_boards() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    win_survival(&client, 1, &player1, &player2, 8_000);
+    assert_eq!(client.get_season().id, 1);
+
+    assert_eq!(client.start_new_season(), 2);
+    assert_eq!(client.get_season(), Season { id: 2, started_at: env.ledger().timestamp() });
+    assert!(client.get_leaderboard(&GameMode::Survival).is_empty());
+    assert!(client.get_player_best(&current_board(&client, GameMode::Survival), &player1).is_none());
+
+    // A worse run still makes the new season's board.
+    win_survival(&client, 2, &player1, &player2, 3_000);
+    assert_eq!(board_times(&client, GameMode::Survival), vec![&env, 3_000]);
+
+    let archived = client.get_season_leaderboard(&1, &GameMode::Survival, &0, &10);
+    assert_eq!(archived.entries.len(), 1);
+    assert_eq!(archived.entries.get_unchecked(0).time_ms, 8_000);
+    assert_eq!(client.get_season_leaderboard(&2, &GameMode::Survival, &0, &10).entries.len(), 1);
+}
+
+#[test]
+fn test_start_new_season_event() {
+    let Setup { env, client, .. } = setup_test();
+    client.start_new_season();
+
+    let started = SeasonStarted { season_id: 2 };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [started.to_xdr(&env, &client.address)]
+    );
+}
+
+#[test]
+fn test_seasons_roll_over_with_time() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    let start = env.ledger().timestamp();
+    client.set_season_length(&Some(30 * DAY));
+
+    advance_time(&env, 30 * DAY - 1);
+    assert_eq!(client.get_season().id, 1);
+    advance_time(&env, 1);
+    assert_eq!(client.get_season(), Season { id: 2, started_at: start + 30 * DAY });
+
+    // Seasons without play still count.
+    advance_time(&env, 65 * DAY);
+    assert_eq!(client.get_season(), Season { id: 4, started_at: start + 90 * DAY });
+
+    win_survival(&client, 1, &player1, &player2, 8_000);
+    assert_eq!(client.get_season_leaderboard(&4, &GameMode::Survival, &0, &10).entries.len(), 1);
+}
+
+#[test]
+fn test_time_rollover_announced_on_next_session() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.set_season_length(&Some(7 * DAY));
+    advance_time(&env, 7 * DAY);
+
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    let started = SeasonStarted { season_id: 2 };
+    let session = SessionStarted { session_id: 1 };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [started.to_xdr(&env, &client.address), session.to_xdr(&env, &client.address)]
+    );
+
+    // Announced once.
+    client.start_game(&2, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    let session = SessionStarted { session_id: 2 };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [session.to_xdr(&env, &client.address)]
+    );
+}
+
+#[test]
+fn test_board_reads_extend_ttl() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    // New entries start short-lived, so the contract's own TTL shows.
+    env.ledger().with_mut(|l| l.min_persistent_entry_ttl = 100);
+    win_survival(&client, 1, &player1, &player2, 8_000);
+    let board = current_board(&client, GameMode::Survival);
+    let ttl = |key: &DataKey| env.as_contract(&client.address, || env.storage().persistent().get_ttl(key));
+    let index_key = DataKey::Leaderboard(board.key());
+    let best_key = DataKey::PlayerBest(board.key(), player1.clone());
+    let full = ttl(&index_key);
+
+    env.ledger().with_mut(|l| l.sequence_number += 1_000);
+    assert_eq!(ttl(&index_key), full - 1_000);
+
+    // A run that does not improve the board writes nothing, but still
+    // keeps what it read alive.
+    win_survival(&client, 2, &player1, &player2, 5_000);
+    assert_eq!(ttl(&index_key), full);
+    assert_eq!(ttl(&best_key), full);
+}
+
+#[test]
+fn test_season_length_change_keeps_current_season() {
+    let Setup { env, client, .. } = setup_test();
+    let start = env.ledger().timestamp();
+    client.set_season_length(&Some(7 * DAY));
+    advance_time(&env, 10 * DAY);
+
+    client.set_season_length(&None);
+    advance_time(&env, 100 * DAY);
+    assert_eq!(client.get_season(), Season { id: 2, started_at: start + 7 * DAY });
+
+    // A manual start resets the clock for a later automatic rollover.
+    client.set_season_length(&Some(7 * DAY));
+    let restarted = env.ledger().timestamp();
+    assert_eq!(client.get_season().id, 16);
+    client.start_new_season();
+    advance_time(&env, 7 * DAY);
+    assert_eq!(client.get_season(), Season { id: 18, started_at: restarted + 7 * DAY });
+}
+
+#[test]
+#[should_panic]
+fn test_start_new_season_requires_admin() {
+    let Setup { env, client, .. } = setup_test();
+    env.set_auths(&[]);
+
+    client.start_new_season();
+}
+
+// ============================================================================
+// Board Key Tests
+// ============================================================================
+
+fn board_of(client: &CubeathonContractClient, mode: GameMode, difficulty: Difficulty, level: u32) -> Vec<u64> {
+    let board = client.get_current_board(&mode, &difficulty, &level);
+    let mut times = Vec::new(&client.env);
+    for entry in client.get_leaderboard_page(&board, &0, &100).entries.iter() {
+        times.push_back(entry.time_ms);
+    }
+    times
+}
+
+#[test]
+fn test_current_board_key() {
+    let Setup { client, .. } = setup_test();
+    client.start_new_season();
+
+    let board = client.get_current_board(&GameMode::Race, &Difficulty::Hard, &2);
+    assert_eq!(board, Board { season: 2, mode: GameMode::Race, difficulty: Difficulty::Hard, level: 2 });
+}
+
+#[test]
+fn test_race_level_boards() {
+    let Setup { env, client, player1, player2 } = setup_with(1, ProofPolicy::Dev);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    clear_levels(&client, 1, &player1, [3_000, 4_000, 5_000], true);
+    clear_levels(&client, 1, &player2, [6_000, 6_000, 6_000], true);
+    client.end_session(&1);
+
+    client.start_game(&2, &player2, &player1, &POINTS, &POINTS, &GameMode::Race, &Difficulty::Normal, &None);
+    clear_levels(&client, 2, &player2, [2_000, 5_000, 4_000], true);
+    clear_levels(&client, 2, &player1, [9_000, 9_000, 9_000], true);
+    client.end_session(&2);
+
+    // Combined time and best single runs rank differently.
+    assert_eq!(board_of(&client, GameMode::Race, Difficulty::Normal, 0), vec![&env, 11_000, 12_000]);
+    assert_eq!(board_of(&client, GameMode::Race, Difficulty::Normal, 1), vec![&env, 2_000, 3_000]);
+    assert_eq!(board_of(&client, GameMode::Race, Difficulty::Normal, 2), vec![&env, 4_000, 5_000]);
+    assert_eq!(board_of(&client, GameMode::Race, Difficulty::Normal, 3), vec![&env, 4_000, 5_000]);
+
+    let board = client.get_current_board(&GameMode::Race, &Difficulty::Normal, &2);
+    assert_eq!(client.get_rank(&board, &player1), Some(1));
+    assert_eq!(client.get_rank(&board, &player2), Some(2));
+}
+
+#[test]
+fn test_survival_level_board_lists_every_proven_run() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    run(&client, 1, &player1, 1, 8_000);
+    run(&client, 1, &player2, 1, 6_000);
+
+    // Listed before the session settles, loser included.
+    assert_eq!(board_of(&client, GameMode::Survival, Difficulty::Normal, 1), vec![&env, 8_000, 6_000]);
+    client.end_session(&1);
+    assert_eq!(board_of(&client, GameMode::Survival, Difficulty::Normal, 0), vec![&env, 8_000]);
+}
+
+#[test]
+fn test_level_board_skips_unproven_runs() {
+    let Setup { env, client, player1, player2 } = setup_with(1, ProofPolicy::Optional);
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Normal, &None);
+    client.submit_score(&1, &player1, &1, &9_000, &nonce(&env), &Bytes::new(&env));
+    run(&client, 1, &player2, 1, 5_000);
+
+    assert_eq!(board_of(&client, GameMode::Survival, Difficulty::Normal, 1), vec![&env, 5_000]);
+}
+
+#[test]
+fn test_difficulties_have_separate_boards() {
+    let Setup { env, client, player1, player2 } = setup_test();
+    client.start_game(&1, &player1, &player2, &POINTS, &POINTS, &GameMode::Survival, &Difficulty::Hard, &None);
+    run(&client, 1, &player1, 1, 5_000);
+    pass_deadline(&env);
+    client.end_session(&1);
+    win_survival(&client, 2, &player1, &player2, 7_000);
+
+    assert_eq!(board_of(&client, GameMode::Survival, Difficulty::Hard, 0), vec![&env, 5_000]);
+    assert_eq!(board_of(&client, GameMode::Survival, Difficulty::Normal, 0), vec![&env, 7_000]);
+    assert!(board_of(&client, GameMode::Survival, Difficulty::Easy, 0).is_empty());
+    assert_eq!(client.get_game(&1).unwrap().difficulty, Difficulty::Hard);
+}
@@ -0,0 +1,307 @@
+//! BN254 point validation for verification keys.
+//!
+//! The pairing host functions trap on a point that is off the curve or, for
+//! G2, outside the prime-order subgroup. A key holding such a point would make
+//! every later `verify` trap instead of returning an `Error`, so
+//! `add_circuit` checks the key's points once with the same rules the host
+//! applies when decoding: both flag bits unset, every coordinate below the
+//! base field modulus, all-zero bytes for the point at infinity.
+//!
+//! Arithmetic is Montgomery form over four little-endian 64-bit limbs; it only
+//! runs at registration, so it favours clarity over speed.
+
+type Limbs = [u64; 4];
+
+/// Base field modulus p.
+const P: Limbs = [0x3c208c16d87cfd47, 0x97816a916871ca8d, 0xb85045b68181585d, 0x30644e72e131a029];
+
+/// Order r of the G1/G2 prime-order subgroup.
+const R: Limbs = [0x43e1f593f0000001, 0x2833e84879b97091, 0xb85045b68181585d, 0x30644e72e131a029];
+
+/// -p⁻¹ mod 2⁶⁴.
+const INV: u64 = 0x87d20782e4866389;
+
+/// 2⁵¹² mod p, converts a canonical value into Montgomery form.
+const R2: Fp = Fp([0xf32cfc5b538afa89, 0xb5e71911d44501fb, 0x47ab1eff0a417ff6, 0x06d89f71cab8351f]);
+
+/// 1 in Montgomery form.
+const ONE: Fp = Fp([0xd35d438dc58f0d9d, 0x0a78eb28f5c70b3d, 0x666ea36f7879462c, 0x0e0a77c19a07df2f]);
+
+/// G1: y² = x³ + 3 (Montgomery form).
+const G1_B: Fp = Fp([0x7a17caa950ad28d7, 0x1f6ac17ae15521b9, 0x334bea4e696bd284, 0x2a1f6744ce179d8e]);
+
+/// G2 twist: y² = x³ + 3 / (9 + u) (Montgomery form).
+const G2_B: Fp2 = Fp2 {
+    c0: Fp([0x3bf938e377b802a8, 0x020b1b273633535d, 0x26b7edf049755260, 0x2514c6324384a86d]),
+    c1: Fp([0x38e7ecccd1dcff67, 0x65f0b37d93ce0d3e, 0xd749d0dd22ac00aa, 0x0141b9ce4a688d4d]),
+};
+
+/// Returns true if `bytes` is the encoding of a point on G1.
+pub fn is_valid_g1(bytes: &[u8; 64]) -> bool {
+    if bytes.iter().all(|b| *b == 0) {
+        return true;
+    }
+    let (Some(x), Some(y)) = (Fp::from_be(&bytes[..32]), Fp::from_be(&bytes[32..])) else {
+        return false;
+    };
+    y.square() == x.square().mul(&x).add(&G1_B)
+}
+
+/// Returns true if `bytes` is the encoding of a point in the order-r
+/// subgroup of G2.
+pub fn is_valid_g2(bytes: &[u8; 128]) -> bool {
+    if bytes.iter().all(|b| *b == 0) {
+        return true;
+    }
+    let (Some(x), Some(y)) = (Fp2::from_be(&bytes[..64]), Fp2::from_be(&bytes[64..])) else {
+        return false;
+    };
+    if y.square() != x.square().mul(&x).add(&G2_B) {
+        return false;
+    }
+    G2Jacobian::mul_by_r(&x, &y).is_infinity()
+}
+
+// ============================================================================
+// Base Field
+// ============================================================================
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Fp(Limbs);
+
+impl Fp {
+    const ZERO: Fp = Fp([0; 4]);
+
+    /// Parse a 32-byte big-endian value; `None` unless it is below p.
+    fn from_be(bytes: &[u8]) -> Option<Fp> {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let start = 32 - 8 * (i + 1);
+            let mut word = [0u8; 8];
+            word.copy_from_slice(&bytes[start..start + 8]);
+            *limb = u64::from_be_bytes(word);
+        }
+        if !less_than(&limbs, &P) {
+            return None;
+        }
+        Some(Fp(limbs).mul(&R2))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == [0; 4]
+    }
+
+    fn add(&self, other: &Fp) -> Fp {
+        // p < 2²⁵⁴, so the sum of two reduced values cannot overflow 256 bits.
+        let (sum, _) = add_limbs(&self.0, &other.0);
+        Fp(reduce_once(sum))
+    }
+
+    fn sub(&self, other: &Fp) -> Fp {
+        let (diff, borrow) = sub_limbs(&self.0, &other.0);
+        if borrow {
+            Fp(add_limbs(&diff, &P).0)
+        } else {
+            Fp(diff)
+        }
+    }
+
+    /// Montgomery multiplication (CIOS).
+    fn mul(&self, other: &Fp) -> Fp {
+        let (a, b) = (&self.0, &other.0);
+        let mut t = [0u64; 6];
+        for &b_i in b.iter() {
+            let mut carry = 0u64;
+            for j in 0..4 {
+                let v = t[j] as u128 + a[j] as u128 * b_i as u128 + carry as u128;
+                t[j] = v as u64;
+                carry = (v >> 64) as u64;
+            }
+            let v = t[4] as u128 + carry as u128;
+            t[4] = v as u64;
+            t[5] = (v >> 64) as u64;
+
+            let m = t[0].wrapping_mul(INV);
+            let v = t[0] as u128 + m as u128 * P[0] as u128;
+            let mut carry = (v >> 64) as u64;
+            for j in 1..4 {
+                let v = t[j] as u128 + m as u128 * P[j] as u128 + carry as u128;
+                t[j - 1] = v as u64;
+                carry = (v >> 64) as u64;
+            }
+            let v = t[4] as u128 + carry as u128;
+            t[3] = v as u64;
+            t[4] = t[5] + (v >> 64) as u64;
+        }
+        let result = [t[0], t[1], t[2], t[3]];
+        if t[4] != 0 {
+            Fp(sub_limbs(&result, &P).0)
+        } else {
+            Fp(reduce_once(result))
+        }
+    }
+
+    fn square(&self) -> Fp {
+        self.mul(self)
+    }
+}
+
+fn less_than(a: &Limbs, b: &Limbs) -> bool {
+    for i in (0..4).rev() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+    }
+    false
+}
+
+fn add_limbs(a: &Limbs, b: &Limbs) -> (Limbs, bool) {
+    let mut out = [0u64; 4];
+    let mut carry = false;
+    for i in 0..4 {
+        let (s, c1) = a[i].overflowing_add(b[i]);
+        let (s, c2) = s.overflowing_add(carry as u64);
+        out[i] = s;
+        carry = c1 || c2;
+    }
+    (out, carry)
+}
+
+fn sub_limbs(a: &Limbs, b: &Limbs) -> (Limbs, bool) {
+    let mut out = [0u64; 4];
+    let mut borrow = false;
+    for i in 0..4 {
+        let (d, b1) = a[i].overflowing_sub(b[i]);
+        let (d, b2) = d.overflowing_sub(borrow as u64);
+        out[i] = d;
+        borrow = b1 || b2;
+    }
+    (out, borrow)
+}
+
+fn reduce_once(value: Limbs) -> Limbs {
+    if less_than(&value, &P) {
+        value
+    } else {
+        sub_limbs(&value, &P).0
+    }
+}
+
+// ============================================================================
+// Quadratic Extension Fp2 = Fp[u] / (u² + 1)
+// ============================================================================
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Fp2 {
+    c0: Fp,
+    c1: Fp,
+}
+
+impl Fp2 {
+    const ZERO: Fp2 = Fp2 { c0: Fp::ZERO, c1: Fp::ZERO };
+    const ONE: Fp2 = Fp2 { c0: ONE, c1: Fp::ZERO };
+
+    /// Parse the 64-byte `c1 ‖ c0` encoding.
+    fn from_be(bytes: &[u8]) -> Option<Fp2> {
+        Some(Fp2 { c0: Fp::from_be(&bytes[32..])?, c1: Fp::from_be(&bytes[..32])? })
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    fn add(&self, other: &Fp2) -> Fp2 {
+        Fp2 { c0: self.c0.add(&other.c0), c1: self.c1.add(&other.c1) }
+    }
+
+    fn sub(&self, other: &Fp2) -> Fp2 {
+        Fp2 { c0: self.c0.sub(&other.c0), c1: self.c1.sub(&other.c1) }
+    }
+
+    fn double(&self) -> Fp2 {
+        self.add(self)
+    }
+
+    fn mul(&self, other: &Fp2) -> Fp2 {
+        let aa = self.c0.mul(&other.c0);
+        let bb = self.c1.mul(&other.c1);
+        let cross = self.c0.add(&self.c1).mul(&other.c0.add(&other.c1));
+        Fp2 { c0: aa.sub(&bb), c1: cross.sub(&aa).sub(&bb) }
+    }
+
+    fn square(&self) -> Fp2 {
+        self.mul(self)
+    }
+}
+
+// ============================================================================
+// G2 Subgroup Check
+// ============================================================================
+
+/// G2 point in Jacobian coordinates; Z = 0 is the point at infinity.
+struct G2Jacobian {
+    x: Fp2,
+    y: Fp2,
+    z: Fp2,
+}
+
+impl G2Jacobian {
+    const INFINITY: G2Jacobian = G2Jacobian { x: Fp2::ONE, y: Fp2::ONE, z: Fp2::ZERO };
+
+    /// `[r](x, y)` by double-and-add; the identity iff the point is in the
+    /// order-r subgroup, as the host's own check decides.
+    fn mul_by_r(x: &Fp2, y: &Fp2) -> G2Jacobian {
+        let mut acc = G2Jacobian::INFINITY;
+        for bit in (0..256).rev() {
+            acc = acc.double();
+            if (R[bit / 64] >> (bit % 64)) & 1 == 1 {
+                acc = acc.add_affine(x, y);
+            }
+        }
+        acc
+    }
+
+    fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// dbl-2009-l (a = 0).
+    fn double(&self) -> G2Jacobian {
+        if self.is_infinity() {
+            return G2Jacobian::INFINITY;
+        }
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+        let d = self.x.add(&b).square().sub(&a).sub(&c).double();
+        let e = a.double().add(&a);
+        let f = e.square();
+        let x3 = f.sub(&d.double());
+        let y3 = e.mul(&d.sub(&x3)).sub(&c.double().double().double());
+        let z3 = self.y.mul(&self.z).double();
+        G2Jacobian { x: x3, y: y3, z: z3 }
+    }
+
+    /// madd-2007-bl: `self + (x2, y2)` with an affine second operand.
+    fn add_affine(&self, x2: &Fp2, y2: &Fp2) -> G2Jacobian {
+        if self.is_infinity() {
+            return G2Jacobian { x: *x2, y: *y2, z: Fp2::ONE };
+        }
+        let z1z1 = self.z.square();
+        let u2 = x2.mul(&z1z1);
+        let s2 = y2.mul(&self.z).mul(&z1z1);
+        let h = u2.sub(&self.x);
+        let rr = s2.sub(&self.y).double();
+        if h.is_zero() {
+            return if rr.is_zero() { self.double() } else { G2Jacobian::INFINITY };
+        }
+        let hh = h.square();
+        let i = hh.double().double();
+        let j = h.mul(&i);
+        let v = self.x.mul(&i);
+        let x3 = rr.square().sub(&j).sub(&v.double());
+        let y3 = rr.mul(&v.sub(&x3)).sub(&self.y.mul(&j).double());
+        let z3 = self.z.add(&h).square().sub(&z1z1).sub(&hh);
+        G2Jacobian { x: x3, y: y3, z: z3 }
+    }
+}
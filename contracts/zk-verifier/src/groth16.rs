@@ -0,0 +1,86 @@
+//! Groth16 backend over BN254, using the Protocol 25 pairing host functions.
+//!
+//! The proof body is `A ‖ B ‖ C` in the Ethereum-compatible uncompressed
+//! encoding accepted by `soroban_sdk::crypto::bn254` (G1: 64 bytes,
+//! G2: 128 bytes). The check is the usual
+//! `e(-A, B) · e(α, β) · e(vk_x, γ) · e(C, δ) == 1`
+//! with `vk_x = IC₀ + Σ xᵢ·ICᵢ₊₁` over the envelope's public inputs.
+
+use soroban_sdk::{
+    crypto::bn254::{Bn254G1Affine, Bn254G2Affine, Fr},
+    vec, BytesN, Env,
+};
+
+use crate::curve;
+use crate::proof::ProofEnvelope;
+use crate::{Error, Groth16VerificationKey};
+
+const G1_SIZE: u32 = 64;
+const G2_SIZE: u32 = 128;
+
+/// Size in bytes of a Groth16 proof body (`A ‖ B ‖ C`).
+pub const PROOF_SIZE: u32 = G1_SIZE + G2_SIZE + G1_SIZE;
+
+/// A key is usable if it has `IC₀` plus at least one public input term and
+/// every point decodes on the host, so `verify` can never trap on the key.
+pub fn is_valid_key(vk: &Groth16VerificationKey) -> bool {
+    vk.ic.len() >= 2
+        && curve::is_valid_g1(&vk.alpha.to_array())
+        && vk.ic.iter().all(|point| curve::is_valid_g1(&point.to_array()))
+        && [&vk.beta, &vk.gamma, &vk.delta]
+            .iter()
+            .all(|point| curve::is_valid_g2(&point.to_array()))
+}
+
+pub fn verify(
+    env: &Env,
+    vk: &Groth16VerificationKey,
+    envelope: &ProofEnvelope,
+) -> Result<(), Error> {
+    if envelope.public_inputs.len() + 1 != vk.ic.len() {
+        return Err(Error::InvalidPublicInput);
+    }
+    if envelope.body.len() != PROOF_SIZE {
+        return Err(Error::MalformedProof);
+    }
+
+    let body = &envelope.body;
+    let a: BytesN<64> = body.slice(0..G1_SIZE).try_into().map_err(|_| Error::MalformedProof)?;
+    let b: BytesN<128> = body
+        .slice(G1_SIZE..G1_SIZE + G2_SIZE)
+        .try_into()
+        .map_err(|_| Error::MalformedProof)?;
+    let c: BytesN<64> = body
+        .slice(G1_SIZE + G2_SIZE..PROOF_SIZE)
+        .try_into()
+        .map_err(|_| Error::MalformedProof)?;
+
+    let bn254 = env.crypto().bn254();
+
+    let mut vk_x = Bn254G1Affine::from_bytes(vk.ic.get_unchecked(0));
+    for (i, input) in envelope.public_inputs.iter().enumerate() {
+        let term = Bn254G1Affine::from_bytes(vk.ic.get_unchecked(i as u32 + 1));
+        let scaled = bn254.g1_mul(&term, &Fr::from_bytes(input));
+        vk_x = bn254.g1_add(&vk_x, &scaled);
+    }
+
+    let g1_points = vec![
+        env,
+        -Bn254G1Affine::from_bytes(a),
+        Bn254G1Affine::from_bytes(vk.alpha.clone()),
+        vk_x,
+        Bn254G1Affine::from_bytes(c),
+    ];
+    let g2_points = vec![
+        env,
+        Bn254G2Affine::from_bytes(b),
+        Bn254G2Affine::from_bytes(vk.beta.clone()),
+        Bn254G2Affine::from_bytes(vk.gamma.clone()),
+        Bn254G2Affine::from_bytes(vk.delta.clone()),
+    ];
+
+    if !bn254.pairing_check(g1_points, g2_points) {
+        return Err(Error::InvalidProof);
+    }
+    Ok(())
+}
@@ -1,14 +1,247 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Bytes, BytesN, Env};
+//! # ZK Verifier
+//!
+//! Checks proofs produced by `zk/cubeathon_circuit` on behalf of the Cubeathon
+//! game contract. `verify` returns a typed `Error` for anything it cannot
+//! positively verify; it never accepts a proof by default.
+//!
+//! ## Circuit Registry
+//! Every `image_id` maps to a `CircuitEntry` holding the verification key
+//! (which also selects the backend) and the circuit version. The admin adds,
+//! deprecates and revokes entries; deprecated circuits still verify so that
+//! in-flight sessions can finish, revoked and unknown ones never do.
+//!
+//! ## Backends
+//! Groth16 only: a BN254 pairing check against the registered key. The
+//! UltraHonk proofs `nargo`/`bb` produce for the circuit have no verifier
+//! here yet.
+
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, Address, Bytes, BytesN,
+    Env, Vec,
+};
+
+mod curve;
+mod groth16;
+mod proof;
+
+#[cfg(test)]
+mod test;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    MalformedProof         = 1,
+    InvalidPublicInput     = 2,
+    InvalidProof           = 4,
+    UnknownCircuit         = 5,
+    InvalidVerificationKey = 6,
+    CircuitRevoked         = 7,
+    CircuitAlreadyExists   = 8,
+    JournalMismatch        = 9,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+/// Groth16 verification key; points use the `crypto::bn254` encoding
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Groth16VerificationKey {
+    pub alpha: BytesN<64>,   // G1
+    pub beta:  BytesN<128>,  // G2
+    pub gamma: BytesN<128>,  // G2
+    pub delta: BytesN<128>,  // G2
+    pub ic:    Vec<BytesN<64>>, // G1, one more than the public input count
+}
+
+/// Verification key for one circuit; the variant selects the backend
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VerificationKey {
+    Groth16(Groth16VerificationKey),
+}
+
+/// Lifecycle of a registered circuit
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CircuitStatus {
+    Active,
+    Deprecated,
+    Revoked,
+}
+
+/// Registry entry for one `image_id`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircuitEntry {
+    pub version: u32,
+    pub key:     VerificationKey,
+    pub status:  CircuitStatus,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Circuit(BytesN<32>), // CircuitEntry, persistent
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[contractevent(topics = ["circuit", "updated"], data_format = "vec")]
+pub struct CircuitUpdated {
+    pub image_id: BytesN<32>,
+    pub version:  u32,
+    pub status:   CircuitStatus,
+}
+
+const INSTANCE_TTL: u32 = 518_400; // ~30 days
+const CIRCUIT_TTL:  u32 = 518_400;
+
+// ============================================================================
+// Contract
+// ============================================================================
 
 #[contract]
 pub struct ZKVerifier;
 
 #[contractimpl]
 impl ZKVerifier {
-    pub fn verify(_env: Env, _proof: Bytes, _image_id: BytesN<32>, _journal_hash: BytesN<32>) {
-        // Mock verifier always succeeds.
-        // In a real scenario, this would call the WASM verification logic.
+
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        circuit: Option<(BytesN<32>, u32, VerificationKey)>,
+    ) -> Result<(), Error> {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        if let Some((image_id, version, key)) = circuit {
+            Self::register(&env, image_id, version, key)?;
+        }
+        Ok(())
+    }
+
+    // ── verify ────────────────────────────────────────────────────────────────
+    /// Verify `proof` (see `proof.rs` for the envelope layout) against the
+    /// circuit registered under `image_id`. The proof's `outcome_hash` public
+    /// input must equal `journal_hash`, so a valid proof for one run cannot be
+    /// paired with another run's claim.
+    pub fn verify(
+        env: Env,
+        proof: Bytes,
+        image_id: BytesN<32>,
+        journal_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        let entry = Self::load(&env, &image_id)?;
+        if entry.status == CircuitStatus::Revoked {
+            return Err(Error::CircuitRevoked);
+        }
+
+        let envelope = proof::decode(&env, &proof)?;
+        proof::bind_journal(&envelope, &journal_hash)?;
+
+        match &entry.key {
+            VerificationKey::Groth16(vk) => groth16::verify(&env, vk, &envelope),
+        }
+    }
+
+    // ── Admin ─────────────────────────────────────────────────────────────────
+
+    /// Register a new circuit under `image_id`.
+    pub fn add_circuit(
+        env: Env,
+        image_id: BytesN<32>,
+        version: u32,
+        key: VerificationKey,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env);
+        Self::register(&env, image_id, version, key)
+    }
+
+    /// Mark a circuit as deprecated; its proofs still verify.
+    pub fn deprecate_circuit(env: Env, image_id: BytesN<32>) -> Result<(), Error> {
+        Self::require_admin(&env);
+        let entry = Self::load(&env, &image_id)?;
+        if entry.status == CircuitStatus::Revoked {
+            return Err(Error::CircuitRevoked);
+        }
+        Self::set_status(&env, image_id, entry, CircuitStatus::Deprecated);
+        Ok(())
+    }
+
+    /// Permanently revoke a circuit; its proofs are rejected from now on.
+    pub fn revoke_circuit(env: Env, image_id: BytesN<32>) -> Result<(), Error> {
+        Self::require_admin(&env);
+        let entry = Self::load(&env, &image_id)?;
+        Self::set_status(&env, image_id, entry, CircuitStatus::Revoked);
+        Ok(())
+    }
+
+    fn require_admin(env: &Env) {
+        let admin: Address = env.storage().instance()
+            .get(&DataKey::Admin)
+            .unwrap();
+        admin.require_auth();
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    }
+
+    fn register(
+        env: &Env,
+        image_id: BytesN<32>,
+        version: u32,
+        key: VerificationKey,
+    ) -> Result<(), Error> {
+        let storage_key = DataKey::Circuit(image_id.clone());
+        if env.storage().persistent().has(&storage_key) {
+            return Err(Error::CircuitAlreadyExists);
+        }
+        match &key {
+            VerificationKey::Groth16(vk) if !groth16::is_valid_key(vk) => {
+                return Err(Error::InvalidVerificationKey);
+            }
+            VerificationKey::Groth16(_) => {}
+        }
+
+        let entry = CircuitEntry { version, key, status: CircuitStatus::Active };
+        env.storage().persistent().set(&storage_key, &entry);
+        env.storage().persistent().extend_ttl(&storage_key, CIRCUIT_TTL, CIRCUIT_TTL);
+
+        CircuitUpdated { image_id, version, status: CircuitStatus::Active }.publish(env);
+        Ok(())
+    }
+
+    fn load(env: &Env, image_id: &BytesN<32>) -> Result<CircuitEntry, Error> {
+        let storage_key = DataKey::Circuit(image_id.clone());
+        let entry = env.storage().persistent()
+            .get(&storage_key)
+            .ok_or(Error::UnknownCircuit)?;
+        env.storage().persistent().extend_ttl(&storage_key, CIRCUIT_TTL, CIRCUIT_TTL);
+        Ok(entry)
+    }
+
+    fn set_status(env: &Env, image_id: BytesN<32>, mut entry: CircuitEntry, status: CircuitStatus) {
+        entry.status = status;
+        env.storage().persistent().set(&DataKey::Circuit(image_id.clone()), &entry);
+        CircuitUpdated { image_id, version: entry.version, status }.publish(env);
+    }
+
+    // ── Queries ───────────────────────────────────────────────────────────────
+
+    pub fn get_circuit(env: Env, image_id: BytesN<32>) -> Option<CircuitEntry> {
+        env.storage().persistent().get(&DataKey::Circuit(image_id))
+    }
+
+    /// Version of the circuit under `image_id`, for callers that only need
+    /// the version and not the whole entry.
+    pub fn get_circuit_version(env: Env, image_id: BytesN<32>) -> Option<u32> {
+        Self::get_circuit(env, image_id).map(|entry| entry.version)
     }
 }
@@ -0,0 +1,82 @@
+//! Proof envelope shared by every backend.
+//!
+//! `bb` emits the public inputs and the proof body as separate artifacts, so
+//! clients concatenate them into the single `proof: Bytes` argument of
+//! `verify`:
+//!
+//! ```text
+//! proof := n: u32 (big-endian)
+//!          ‖ n × 32-byte big-endian BN254 scalar field elements (public inputs)
+//!          ‖ proof body (backend specific)
+//! ```
+//!
+//! The circuit declares `outcome_hash` as its last public input, so the last
+//! public input is the journal the proof commits to.
+
+use soroban_sdk::{Bytes, BytesN, Env, Vec};
+
+use crate::Error;
+
+/// Size in bytes of one serialized BN254 scalar field element.
+pub const FIELD_SIZE: u32 = 32;
+
+/// BN254 scalar field modulus r, big-endian.
+const FR_MODULUS_BE: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// Decoded proof envelope.
+pub struct ProofEnvelope {
+    pub public_inputs: Vec<BytesN<32>>,
+    pub body:          Bytes,
+}
+
+/// Returns true if `value` is a canonical (fully reduced) BN254 scalar.
+pub fn is_canonical_fr(value: &BytesN<32>) -> bool {
+    value.to_array() < FR_MODULUS_BE
+}
+
+/// Split `proof` into its public inputs and backend-specific body.
+pub fn decode(env: &Env, proof: &Bytes) -> Result<ProofEnvelope, Error> {
+    if proof.len() < 4 {
+        return Err(Error::MalformedProof);
+    }
+    let mut count_bytes = [0u8; 4];
+    proof.slice(0..4).copy_into_slice(&mut count_bytes);
+    let count = u32::from_be_bytes(count_bytes);
+
+    let inputs_len = count
+        .checked_mul(FIELD_SIZE)
+        .and_then(|len| len.checked_add(4))
+        .ok_or(Error::MalformedProof)?;
+    if proof.len() <= inputs_len {
+        return Err(Error::MalformedProof);
+    }
+
+    let mut public_inputs = Vec::new(env);
+    for i in 0..count {
+        let start = 4 + i * FIELD_SIZE;
+        let input: BytesN<32> = proof
+            .slice(start..start + FIELD_SIZE)
+            .try_into()
+            .map_err(|_| Error::MalformedProof)?;
+        if !is_canonical_fr(&input) {
+            return Err(Error::InvalidPublicInput);
+        }
+        public_inputs.push_back(input);
+    }
+
+    Ok(ProofEnvelope {
+        public_inputs,
+        body: proof.slice(inputs_len..),
+    })
+}
+
+/// Check that the proof commits to `journal_hash`.
+pub fn bind_journal(envelope: &ProofEnvelope, journal_hash: &BytesN<32>) -> Result<(), Error> {
+    match envelope.public_inputs.last() {
+        Some(outcome_hash) if outcome_hash == *journal_hash => Ok(()),
+        _ => Err(Error::JournalMismatch),
+    }
+}
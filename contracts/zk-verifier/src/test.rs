@@ -1,112 +1,118 @@
 #![cfg(test)]
 
-// Unit tests for the zk-verifier contract using a simple mock GameHub.
-// These tests verify game logic independently of the full GameHub system.
+// Unit tests for the zk-verifier contract.
 //
-// Note: These tests use a minimal mock for isolation and speed.
-// For full integration tests with the real Game Hub contract, see the platform repo.
+// Envelope fixtures are hand-assembled; they exercise decoding and rejection
+// paths independently of any proving toolchain. The Groth16 fixture is a toy
+// key generated off-chain from known trapdoor scalars, together with a proof
+// that satisfies the pairing equation for one public input.
 
-use crate::{Error, ZkVerifierContract, ZkVerifierContractClient};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use crate::{
+    CircuitStatus, Error, Groth16VerificationKey, VerificationKey, ZKVerifier, ZKVerifierClient,
+};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env};
 
 // ============================================================================
-// Mock GameHub for Unit Testing
+// Groth16 Fixtures
 // ============================================================================
 
-#[contract]
-pub struct MockGameHub;
-
-#[contractimpl]
-impl MockGameHub {
-    pub fn start_game(
-        _env: Env,
-        _game_id: Address,
-        _session_id: u32,
-        _player1: Address,
-        _player2: Address,
-        _player1_points: i128,
-        _player2_points: i128,
-    ) {
-        // Mock implementation - does nothing
+const VK_ALPHA: &str = "2a14705537b009189da8808651eecdb82482477fe92ac12ca8b71f80fc3d49ef2df7ee7f243ea8b38e1ddf14029258877a618c779fd4717db6177e19ea67ec38";
+const VK_BETA: &str = "009edaf0698a8c56f51139588acc094cee3c37d427bb6d2eab830aae529097d123ad66f3a7cca9dc75049635faebd124316244b91de5fb2764cd151572a905f72700e8a29b7bb45f3022a18a07bdc66d0254559e17cce64e3b4ad21578fcf4101ad4f87d3b4375a39988ac099b042b1e7c0c715678e4c2bea8905f607cf950f8";
+const VK_GAMMA: &str = "227071bba5ff3b47ed8b504bb5b215bc701d7a3259b933bff1a4164eae499c2c0c51a367b61d3119677b29739ddccbb78002b5558d8f49ff16e299c1b41f809808bb188b2a6187bb1e87834c85a6a917763d65b98febf2c45ea339dd77fac41518fd2fd13be8494c39e8a91325d1ef3ba7d1a205d10788e38bc9e09d9be87769";
+const VK_DELTA: &str = "25407be35f18c6594174374841311466c0e66ff003762448c06bca4fa5e9c54e15cbba9ab73bc73d0ba4ad132a15cb0c73107a9c19b040c4c73d89f6bf75404d1edef86c1a42fa85ab6ae8d268a7e9b46890b2130dd83b91c86c504cf1f93fbf2c750c045112e4ab07f18b12475309cebdcb726bda1ca9948bacd498a28cf411";
+const VK_IC0: &str = "1e28260f0ee971dec1e84cf81ff2776ad314d2cfb9ef81d4c970620c29b811f128fc8a72d4ff12654c3c39dab54eaef9638d28de738959779fcd3e7ac918b396";
+const VK_IC1: &str = "1605ffc1ea2e1aef15d774d3207176420c5cc454b19b55558562b0c7ddf00a7d0cf605873faa8028df38ec2d0800d5ddc67f1776338d675491fe87f6bb7354b3";
+
+/// Public input the fixture proof was generated for.
+const PROOF_INPUT: u8 = 7;
+const PROOF_A: &str = "14b4fa251277a6f4cbbfe379a152a976641f58a4a2bffd3b677ea093bdad853c28ce094a6d16280abcf8d84efa062c85511819dd87d8da255885ce0580ebee36";
+const PROOF_B: &str = "0476be093a6d2b4bbf907172049874af11e1b6267606e00804d3ff0037ec57fd3010c68cb50161b7d1d96bb71edfec9880171954e56871abf3d93cc94d745fa114c059d74e5b6c4ec14ae5864ebe23a71781d86c29fb8fb6cce94f70d3de7a2101b33461f39d9e887dbb100f170a2345dde3c07e256d1dfa2b657ba5cd030427";
+const PROOF_C: &str = "04feae8fef888d0db9888814d2d59b46fd279da271a6d7abaea86062b8d3c73322ec0c86dca1111d406ad13c30ff43b532f95f3127695015d15162fb04e975fe";
+
+/// On the G2 twist but outside the order-r subgroup.
+const G2_OUTSIDE_SUBGROUP: &str = "2cac03b74197c64819fb7cccb3faeef3c90ceb7d381fc8c0a7a431ddd847aa78090c51cae0ef909dc72784c2636edff4bd9463337013913e51043d1c5413d33610b0b18e9052c08bc19a3caa1badcc6b5f0468ac34584ecea48c5988c017fd9d2d0e8cbdf43bebecd3ad00b8e5e0051645ca6743a4bdcf5e585de86db43190ec";
+
+/// BN254 base field modulus p, big-endian.
+const FP_MODULUS: &str = "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47";
+
+fn hex<const N: usize>(s: &str) -> [u8; N] {
+    let digits = s.as_bytes();
+    assert_eq!(digits.len(), N * 2, "fixture has the wrong length");
+    let nibble = |c: u8| (c as char).to_digit(16).unwrap() as u8;
+    let mut out = [0u8; N];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = (nibble(digits[2 * i]) << 4) | nibble(digits[2 * i + 1]);
     }
+    out
+}
 
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
-        // Mock implementation - does nothing
+fn fixture_vk(env: &Env) -> Groth16VerificationKey {
+    Groth16VerificationKey {
+        alpha: BytesN::from_array(env, &hex(VK_ALPHA)),
+        beta:  BytesN::from_array(env, &hex(VK_BETA)),
+        gamma: BytesN::from_array(env, &hex(VK_GAMMA)),
+        delta: BytesN::from_array(env, &hex(VK_DELTA)),
+        ic:    vec![
+            env,
+            BytesN::from_array(env, &hex(VK_IC0)),
+            BytesN::from_array(env, &hex(VK_IC1)),
+        ],
     }
+}
 
-    pub fn add_game(_env: Env, _game_address: Address) {
-        // Mock implementation - does nothing
-    }
+/// Groth16 body `A ‖ B ‖ C` of the fixture proof.
+fn fixture_body() -> [u8; 256] {
+    let mut body = [0u8; 256];
+    body[..64].copy_from_slice(&hex::<64>(PROOF_A));
+    body[64..192].copy_from_slice(&hex::<128>(PROOF_B));
+    body[192..].copy_from_slice(&hex::<64>(PROOF_C));
+    body
 }
 
 // ============================================================================
 // Test Helpers
 // ============================================================================
 
-fn setup_test() -> (
-    Env,
-    ZkVerifierContractClient<'static>,
-    MockGameHubClient<'static>,
-    Address,
-    Address,
-) {
+/// Deploy with `key` registered as version 1 under image id `[1; 32]`.
+fn setup_with_key(key: fn(&Env) -> VerificationKey) -> (Env, ZKVerifierClient<'static>) {
     let env = Env::default();
     env.mock_all_auths();
 
-    // Set ledger info for time-based operations
-    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
-        timestamp: 1441065600,
-        protocol_version: 25,
-        sequence_number: 100,
-        network_id: Default::default(),
-        base_reserve: 10,
-        min_temp_entry_ttl: u32::MAX / 2,
-        min_persistent_entry_ttl: u32::MAX / 2,
-        max_entry_ttl: u32::MAX / 2,
-    });
-
-    // Deploy mock GameHub contract
-    let hub_addr = env.register(MockGameHub, ());
-    let game_hub = MockGameHubClient::new(&env, &hub_addr);
-
-    // Create admin address
     let admin = Address::generate(&env);
+    let image_id = BytesN::from_array(&env, &[1u8; 32]);
+    let circuit = Some((image_id, 1u32, key(&env)));
+    let contract_id = env.register(ZKVerifier, (&admin, circuit));
+    let client = ZKVerifierClient::new(&env, &contract_id);
+    (env, client)
+}
 
-    // Deploy zk-verifier with admin and GameHub address
-    let contract_id = env.register(ZkVerifierContract, (&admin, &hub_addr));
-    let client = ZkVerifierContractClient::new(&env, &contract_id);
-
-    // Register zk-verifier as a whitelisted game (mock does nothing)
-    game_hub.add_game(&contract_id);
+fn setup_test() -> (Env, ZKVerifierClient<'static>) {
+    setup_with_key(|env| VerificationKey::Groth16(fixture_vk(env)))
+}
 
-    let player1 = Address::generate(&env);
-    let player2 = Address::generate(&env);
+/// Build a proof envelope from public inputs and a raw body.
+fn envelope(env: &Env, public_inputs: &[[u8; 32]], body: &[u8]) -> Bytes {
+    let mut proof = Bytes::from_array(env, &(public_inputs.len() as u32).to_be_bytes());
+    for input in public_inputs {
+        proof.extend_from_array(input);
+    }
+    proof.extend_from_slice(body);
+    proof
+}
 
-    (env, client, game_hub, player1, player2)
+fn field(value: u8) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes[31] = value;
+    bytes
 }
 
-/// Assert that a Result contains a specific number_guess error
-///
-/// This helper provides type-safe error assertions following Stellar/Soroban best practices.
-/// Instead of using `assert_eq!(result, Err(Ok(Error::AlreadyGuessed)))`, this pattern:
-/// - Provides compile-time error checking
-/// - Makes tests more readable with named errors
-/// - Gives better failure messages
+/// Assert that a Result contains a specific verifier error
 ///
-/// # Example
-/// ```
-/// let result = client.try_make_guess(&session_id, &player, &7);
-/// assert_number_guess_error(&result, Error::AlreadyGuessed);
-/// ```
-///
-/// # Type Signature
 /// The try_ methods return: `Result<Result<T, T::Error>, Result<E, InvokeError>>`
-/// - Ok(Ok(value)): Call succeeded, decode succeeded
-/// - Ok(Err(conv_err)): Call succeeded, decode failed
 /// - Err(Ok(error)): Contract reverted with custom error (THIS IS WHAT WE TEST)
-/// - Err(Err(invoke_err)): Low-level invocation failure
-fn assert_number_guess_error<T, E>(
+/// - anything else is a test failure
+fn assert_verifier_error<T, E>(
     result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
     expected_error: Error,
 ) {
@@ -140,400 +146,317 @@ fn assert_number_guess_error<T, E>(
 }
 
 // ============================================================================
-// Basic Game Flow Tests
+// Envelope Decoding Tests
 // ============================================================================
 
 #[test]
-fn test_complete_game() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-
-    let session_id = 1u32;
-    let points = 100_0000000;
+fn test_empty_proof_is_malformed() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[1u8; 32]);
+    let journal = BytesN::from_array(&env, &field(7));
 
-    // Start game
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    let result = client.try_verify(&Bytes::new(&env), &image_id, &journal);
+    assert_verifier_error(&result, Error::MalformedProof);
+}
 
-    // Get game to verify state
-    let game = client.get_game(&session_id);
-    assert!(game.winning_number.is_none()); // Winning number not set yet
-    assert!(game.winner.is_none()); // Game is still active
-    assert_eq!(game.player1, player1);
-    assert_eq!(game.player2, player2);
-    assert_eq!(game.player1_points, points);
-    assert_eq!(game.player2_points, points);
+#[test]
+fn test_truncated_public_inputs_are_malformed() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[1u8; 32]);
+    let journal = BytesN::from_array(&env, &field(7));
 
-    // Make guesses
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    // Claims two public inputs but only carries one and no body.
+    let mut proof = envelope(&env, &[field(7)], &[]);
+    proof.set(3, 2);
 
-    // Reveal winner
-    let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
+    let result = client.try_verify(&proof, &image_id, &journal);
+    assert_verifier_error(&result, Error::MalformedProof);
+}
 
-    // Verify game is ended and winning number is now set
-    let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some()); // Game has ended
-    assert_eq!(final_game.winner.unwrap(), winner);
-    assert!(final_game.winning_number.is_some());
-    let winning_number = final_game.winning_number.unwrap();
-    assert!(winning_number >= 1 && winning_number <= 10);
+#[test]
+fn test_missing_body_is_malformed() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[1u8; 32]);
+    let journal = BytesN::from_array(&env, &field(7));
+
+    let proof = envelope(&env, &[field(7)], &[]);
+    let result = client.try_verify(&proof, &image_id, &journal);
+    assert_verifier_error(&result, Error::MalformedProof);
 }
 
 #[test]
-fn test_winning_number_in_range() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_non_canonical_public_input_rejected() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[1u8; 32]);
+    let journal = BytesN::from_array(&env, &field(7));
+
+    let proof = envelope(&env, &[[0xff; 32]], &[0u8; 32]);
+    let result = client.try_verify(&proof, &image_id, &journal);
+    assert_verifier_error(&result, Error::InvalidPublicInput);
+}
 
-    let session_id = 2u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+// ============================================================================
+// Groth16 Backend Tests
+// ============================================================================
 
-    // Make guesses and reveal winner to generate winning number
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
-    client.reveal_winner(&session_id);
+#[test]
+fn test_groth16_fixture_proof_verifies() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[1u8; 32]);
+    let journal = BytesN::from_array(&env, &field(PROOF_INPUT));
 
-    let game = client.get_game(&session_id);
-    let winning_number = game
-        .winning_number
-        .expect("Winning number should be set after reveal");
-    assert!(
-        winning_number >= 1 && winning_number <= 10,
-        "Winning number should be between 1 and 10"
-    );
+    let proof = envelope(&env, &[field(PROOF_INPUT)], &fixture_body());
+    client.verify(&proof, &image_id, &journal);
 }
 
 #[test]
-fn test_multiple_sessions() {
-    let (env, client, _hub, player1, player2) = setup_test();
-    let player3 = Address::generate(&env);
-    let player4 = Address::generate(&env);
-
-    let session1 = 3u32;
-    let session2 = 4u32;
+fn test_groth16_wrong_public_input_rejected() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[1u8; 32]);
+    let journal = BytesN::from_array(&env, &field(PROOF_INPUT + 1));
+
+    let proof = envelope(&env, &[field(PROOF_INPUT + 1)], &fixture_body());
+    let result = client.try_verify(&proof, &image_id, &journal);
+    assert_verifier_error(&result, Error::InvalidProof);
+}
 
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+#[test]
+fn test_groth16_tampered_proof_rejected() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[1u8; 32]);
+    let journal = BytesN::from_array(&env, &field(PROOF_INPUT));
+
+    // Swap A and C: both stay valid curve points but the equation breaks.
+    let mut body = fixture_body();
+    body[..64].copy_from_slice(&hex::<64>(PROOF_C));
+    body[192..].copy_from_slice(&hex::<64>(PROOF_A));
+
+    let proof = envelope(&env, &[field(PROOF_INPUT)], &body);
+    let result = client.try_verify(&proof, &image_id, &journal);
+    assert_verifier_error(&result, Error::InvalidProof);
+}
 
-    // Verify both games exist and are independent
-    let game1 = client.get_game(&session1);
-    let game2 = client.get_game(&session2);
+#[test]
+fn test_groth16_wrong_body_length_is_malformed() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[1u8; 32]);
+    let journal = BytesN::from_array(&env, &field(PROOF_INPUT));
+
+    let proof = envelope(&env, &[field(PROOF_INPUT)], &fixture_body()[..192]);
+    let result = client.try_verify(&proof, &image_id, &journal);
+    assert_verifier_error(&result, Error::MalformedProof);
+}
 
-    assert_eq!(game1.player1, player1);
-    assert_eq!(game2.player1, player3);
+#[test]
+fn test_groth16_public_input_count_must_match_key() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[1u8; 32]);
+    let journal = BytesN::from_array(&env, &field(PROOF_INPUT));
+
+    let proof = envelope(&env, &[field(1), field(PROOF_INPUT)], &fixture_body());
+    let result = client.try_verify(&proof, &image_id, &journal);
+    assert_verifier_error(&result, Error::InvalidPublicInput);
 }
 
 // ============================================================================
-// Guess Logic Tests
+// Journal Binding Tests
 // ============================================================================
 
 #[test]
-fn test_closest_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-
-    let session_id = 5u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
-
-    // Player1 guesses closer (1 away from any number between 1-10)
-    // Player2 guesses further (at least 2 away)
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &10);
-
-    let winner = client.reveal_winner(&session_id);
-
-    // Get the final game state to check the winning number
-    let game = client.get_game(&session_id);
-    let winning_number = game.winning_number.unwrap();
-
-    // Calculate which player should have won based on distances
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
-
-    let expected_winner = if distance1 <= distance2 {
-        player1.clone()
-    } else {
-        player2.clone()
-    };
-    assert_eq!(
-        winner, expected_winner,
-        "Player with closer guess should win"
-    );
+fn test_valid_proof_with_other_journal_rejected() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[1u8; 32]);
+    let other_journal = BytesN::from_array(&env, &field(PROOF_INPUT + 1));
+
+    // The proof itself is valid, but it commits to a different outcome.
+    let proof = envelope(&env, &[field(PROOF_INPUT)], &fixture_body());
+    let result = client.try_verify(&proof, &image_id, &other_journal);
+    assert_verifier_error(&result, Error::JournalMismatch);
 }
 
 #[test]
-fn test_tie_game_player1_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-
-    let session_id = 6u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
-
-    // Both players guess the same number (guaranteed tie)
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &5);
+fn test_non_canonical_journal_never_matches() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[1u8; 32]);
+    let journal = BytesN::from_array(&env, &[0xff; 32]);
+
+    let proof = envelope(&env, &[field(PROOF_INPUT)], &fixture_body());
+    let result = client.try_verify(&proof, &image_id, &journal);
+    assert_verifier_error(&result, Error::JournalMismatch);
+}
 
-    let winner = client.reveal_winner(&session_id);
-    assert_eq!(winner, player1, "Player1 should win in a tie");
+#[test]
+fn test_journal_checked_before_backend() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[1u8; 32]);
+    let journal = BytesN::from_array(&env, &field(8));
+
+    let proof = envelope(&env, &[field(7)], &[0u8; 64]);
+    let result = client.try_verify(&proof, &image_id, &journal);
+    assert_verifier_error(&result, Error::JournalMismatch);
 }
 
 #[test]
-fn test_exact_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-
-    let session_id = 7u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
-
-    // Player1 guesses 5 (middle), player2 guesses 10 (edge)
-    // Player1 is more likely to be closer to the winning number
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &10);
-
-    let winner = client.reveal_winner(&session_id);
-    let game = client.get_game(&session_id);
-    let winning_number = game.winning_number.unwrap();
-
-    // Verify the winner matches the distance calculation
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
-    let expected_winner = if distance1 <= distance2 {
-        player1.clone()
-    } else {
-        player2.clone()
-    };
-    assert_eq!(winner, expected_winner);
+fn test_proof_without_public_inputs_rejected() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[1u8; 32]);
+    let journal = BytesN::from_array(&env, &field(PROOF_INPUT));
+
+    let proof = envelope(&env, &[], &fixture_body());
+    let result = client.try_verify(&proof, &image_id, &journal);
+    assert_verifier_error(&result, Error::JournalMismatch);
 }
 
 // ============================================================================
-// Error Handling Tests
+// Circuit Registry Tests
 // ============================================================================
 
 #[test]
-fn test_cannot_guess_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-
-    let session_id = 8u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
-
-    // Make first guess
-    client.make_guess(&session_id, &player1, &5);
-
-    // Try to guess again - should fail
-    let result = client.try_make_guess(&session_id, &player1, &6);
-    assert_number_guess_error(&result, Error::AlreadyGuessed);
+fn test_unknown_image_id_rejected() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[9u8; 32]);
+    let journal = BytesN::from_array(&env, &field(PROOF_INPUT));
+
+    let proof = envelope(&env, &[field(PROOF_INPUT)], &fixture_body());
+    let result = client.try_verify(&proof, &image_id, &journal);
+    assert_verifier_error(&result, Error::UnknownCircuit);
 }
 
 #[test]
-fn test_cannot_reveal_before_both_guesses() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-
-    let session_id = 9u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
-
-    // Only player1 guesses
-    client.make_guess(&session_id, &player1, &5);
-
-    // Try to reveal winner - should fail
-    let result = client.try_reveal_winner(&session_id);
-    assert_number_guess_error(&result, Error::BothPlayersNotGuessed);
+fn test_admin_adds_circuit() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[2u8; 32]);
+    assert!(client.get_circuit(&image_id).is_none());
+    assert_eq!(client.get_circuit_version(&image_id), None);
+
+    client.add_circuit(&image_id, &2, &VerificationKey::Groth16(fixture_vk(&env)));
+    let entry = client.get_circuit(&image_id).unwrap();
+    assert_eq!(entry.version, 2);
+    assert_eq!(client.get_circuit_version(&image_id), Some(2));
+    assert_eq!(entry.status, CircuitStatus::Active);
+
+    let journal = BytesN::from_array(&env, &field(PROOF_INPUT));
+    let proof = envelope(&env, &[field(PROOF_INPUT)], &fixture_body());
+    client.verify(&proof, &image_id, &journal);
 }
 
 #[test]
-#[should_panic(expected = "Guess must be between 1 and 10")]
-fn test_cannot_guess_below_range() {
-    let (env, client, _hub, player1, _player2) = setup_test();
+fn test_duplicate_image_id_rejected() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[1u8; 32]);
 
-    let session_id = 10u32;
-    client.start_game(
-        &session_id,
-        &player1,
-        &Address::generate(&env),
-        &100_0000000,
-        &100_0000000,
-    );
-
-    // Try to guess 0 (below range) - should panic
-    client.make_guess(&session_id, &player1, &0);
+    let result = client.try_add_circuit(&image_id, &2, &VerificationKey::Groth16(fixture_vk(&env)));
+    assert_verifier_error(&result, Error::CircuitAlreadyExists);
 }
 
 #[test]
-#[should_panic(expected = "Guess must be between 1 and 10")]
-fn test_cannot_guess_above_range() {
-    let (env, client, _hub, player1, _player2) = setup_test();
+fn test_key_without_public_inputs_rejected() {
+    let (env, client) = setup_test();
+    let mut vk = fixture_vk(&env);
+    vk.ic = vec![&env, BytesN::from_array(&env, &hex(VK_IC0))];
+
+    let image_id = BytesN::from_array(&env, &[2u8; 32]);
+    let result = client.try_add_circuit(&image_id, &2, &VerificationKey::Groth16(vk));
+    assert_verifier_error(&result, Error::InvalidVerificationKey);
+}
 
-    let session_id = 11u32;
-    client.start_game(
-        &session_id,
-        &player1,
-        &Address::generate(&env),
-        &100_0000000,
-        &100_0000000,
-    );
+/// Flip the lowest bit of a point's last coordinate, moving it off the curve.
+fn off_curve<const N: usize>(point: &str) -> [u8; N] {
+    let mut bytes = hex::<N>(point);
+    bytes[N - 1] ^= 1;
+    bytes
+}
 
-    // Try to guess 11 (above range) - should panic
-    client.make_guess(&session_id, &player1, &11);
+fn assert_key_rejected(env: &Env, client: &ZKVerifierClient, vk: Groth16VerificationKey) {
+    let image_id = BytesN::from_array(env, &[2u8; 32]);
+    let result = client.try_add_circuit(&image_id, &2, &VerificationKey::Groth16(vk));
+    assert_verifier_error(&result, Error::InvalidVerificationKey);
+    assert!(client.get_circuit(&image_id).is_none());
 }
 
 #[test]
-fn test_non_player_cannot_guess() {
-    let (env, client, _hub, player1, player2) = setup_test();
-    let non_player = Address::generate(&env);
-
-    let session_id = 11u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
-
-    // Non-player tries to guess
-    let result = client.try_make_guess(&session_id, &non_player, &5);
-    assert_number_guess_error(&result, Error::NotPlayer);
+fn test_key_with_off_curve_g1_point_rejected() {
+    let (env, client) = setup_test();
+    let mut vk = fixture_vk(&env);
+    vk.alpha = BytesN::from_array(&env, &off_curve(VK_ALPHA));
+    assert_key_rejected(&env, &client, vk);
+
+    let mut vk = fixture_vk(&env);
+    vk.ic.set(1, BytesN::from_array(&env, &off_curve(VK_IC1)));
+    assert_key_rejected(&env, &client, vk);
 }
 
 #[test]
-fn test_cannot_reveal_nonexistent_game() {
-    let (_env, client, _hub, _player1, _player2) = setup_test();
-
-    let result = client.try_reveal_winner(&999);
-    assert_number_guess_error(&result, Error::GameNotFound);
+fn test_key_with_off_curve_g2_point_rejected() {
+    let (env, client) = setup_test();
+    let mut vk = fixture_vk(&env);
+    vk.delta = BytesN::from_array(&env, &off_curve(VK_DELTA));
+    assert_key_rejected(&env, &client, vk);
 }
 
 #[test]
-fn test_cannot_guess_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-
-    let session_id = 12u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
-
-    // Both players make guesses
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
-
-    // Reveal winner - game ends
-    let _winner = client.reveal_winner(&session_id);
-
-    // Try to make another guess after game has ended - should fail
-    let result = client.try_make_guess(&session_id, &player1, &3);
-    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+fn test_key_with_g2_point_outside_subgroup_rejected() {
+    let (env, client) = setup_test();
+    let mut vk = fixture_vk(&env);
+    vk.gamma = BytesN::from_array(&env, &hex(G2_OUTSIDE_SUBGROUP));
+    assert_key_rejected(&env, &client, vk);
 }
 
 #[test]
-fn test_cannot_reveal_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-
-    let session_id = 14u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
-
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
-
-    // First reveal succeeds
-    let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
-
-    // Second reveal should return same winner (idempotent)
-    let winner2 = client.reveal_winner(&session_id);
-    assert_eq!(winner, winner2);
+fn test_key_with_non_canonical_coordinate_rejected() {
+    let (env, client) = setup_test();
+    let mut alpha = hex::<64>(VK_ALPHA);
+    alpha[..32].copy_from_slice(&hex::<32>(FP_MODULUS));
+    let mut vk = fixture_vk(&env);
+    vk.alpha = BytesN::from_array(&env, &alpha);
+    assert_key_rejected(&env, &client, vk);
 }
 
-// ============================================================================
-// Multiple Games Tests
-// ============================================================================
-
 #[test]
-fn test_multiple_games_independent() {
-    let (env, client, _hub, player1, player2) = setup_test();
-    let player3 = Address::generate(&env);
-    let player4 = Address::generate(&env);
-
-    let session1 = 20u32;
-    let session2 = 21u32;
-
-    // Start two games
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
-
-    // Play both games independently
-    client.make_guess(&session1, &player1, &3);
-    client.make_guess(&session2, &player3, &8);
-    client.make_guess(&session1, &player2, &7);
-    client.make_guess(&session2, &player4, &2);
-
-    // Reveal both winners
-    let winner1 = client.reveal_winner(&session1);
-    let winner2 = client.reveal_winner(&session2);
-
-    assert!(winner1 == player1 || winner1 == player2);
-    assert!(winner2 == player3 || winner2 == player4);
-
-    // Verify both games are independent
-    let final_game1 = client.get_game(&session1);
-    let final_game2 = client.get_game(&session2);
-
-    assert!(final_game1.winner.is_some()); // Game 1 has ended
-    assert!(final_game2.winner.is_some()); // Game 2 has ended
-
-    // Note: winning numbers could be the same by chance, so we just verify they're both set
-    assert!(final_game1.winning_number.is_some());
-    assert!(final_game2.winning_number.is_some());
+fn test_deprecated_circuit_still_verifies() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[1u8; 32]);
+    client.deprecate_circuit(&image_id);
+    assert_eq!(client.get_circuit(&image_id).unwrap().status, CircuitStatus::Deprecated);
+
+    let journal = BytesN::from_array(&env, &field(PROOF_INPUT));
+    let proof = envelope(&env, &[field(PROOF_INPUT)], &fixture_body());
+    client.verify(&proof, &image_id, &journal);
 }
 
 #[test]
-fn test_asymmetric_points() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-
-    let session_id = 15u32;
-    let points1 = 200_0000000;
-    let points2 = 50_0000000;
-
-    client.start_game(&session_id, &player1, &player2, &points1, &points2);
-
-    let game = client.get_game(&session_id);
-    assert_eq!(game.player1_points, points1);
-    assert_eq!(game.player2_points, points2);
+fn test_revoked_circuit_rejected() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[1u8; 32]);
+    client.revoke_circuit(&image_id);
+
+    let journal = BytesN::from_array(&env, &field(PROOF_INPUT));
+    let proof = envelope(&env, &[field(PROOF_INPUT)], &fixture_body());
+    let result = client.try_verify(&proof, &image_id, &journal);
+    assert_verifier_error(&result, Error::CircuitRevoked);
+
+    // Revocation is terminal.
+    let result = client.try_deprecate_circuit(&image_id);
+    assert_verifier_error(&result, Error::CircuitRevoked);
+}
 
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &5);
-    client.reveal_winner(&session_id);
+#[test]
+fn test_unknown_circuit_cannot_be_revoked() {
+    let (env, client) = setup_test();
+    let image_id = BytesN::from_array(&env, &[9u8; 32]);
 
-    // Game completes successfully with asymmetric points
-    let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some()); // Game has ended
+    let result = client.try_revoke_circuit(&image_id);
+    assert_verifier_error(&result, Error::UnknownCircuit);
 }
 
-// ============================================================================
-// Admin Function Tests
-// ============================================================================
-
 #[test]
-fn test_upgrade_function_exists() {
+#[should_panic]
+fn test_add_circuit_requires_admin() {
     let env = Env::default();
-    env.mock_all_auths();
-
     let admin = Address::generate(&env);
-    let hub_addr = env.register(MockGameHub, ());
-
-    // Deploy zk-verifier with admin
-    let contract_id = env.register(ZkVerifierContract, (&admin, &hub_addr));
-    let client = ZkVerifierContractClient::new(&env, &contract_id);
-
-    // Verify the upgrade function exists and can be called
-    // Note: We can't test actual upgrade without real WASM files
-    // The function will fail with MissingValue because the WASM hash doesn't exist
-    // But that's expected - we're just verifying the function signature is correct
-    let new_wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
-    let result = client.try_upgrade(&new_wasm_hash);
-
-    // Should fail with MissingValue (WASM doesn't exist) not NotAdmin
-    // This confirms the authorization check passed
-    assert!(result.is_err());
+    let contract_id = env.register(ZKVerifier, (&admin, None::<(BytesN<32>, u32, VerificationKey)>));
+    let client = ZKVerifierClient::new(&env, &contract_id);
+
+    let image_id = BytesN::from_array(&env, &[2u8; 32]);
+    client.add_circuit(&image_id, &1, &VerificationKey::Groth16(fixture_vk(&env)));
 }
@@ -1,8 +1,8 @@
 import { useState, useCallback, useEffect, memo } from 'react';
-import { CubeathonGame } from './games/cubeathon/CubeathonGame';
+import { CubeathonGame, type Difficulty as RunDifficulty } from './games/cubeathon/CubeathonGame';
 import studioLogo from './assets/logo.svg';
 import { useWallet } from './hooks/useWallet';
-import { cubeathonService } from './services/cubeathonService';
+import { cubeathonService, type Difficulty } from './services/cubeathonService';
 import { devWalletService, DevWalletService } from './services/devWalletService';
 import { rpc as StellarRpc } from '@stellar/stellar-sdk';
 import './App.css';
@@ -83,11 +83,6 @@ const AppHeader = memo(function AppHeader({
   );
 });
 
-const createSessionId = () => {
-  const ts = Math.floor(Date.now() / 1000) % 1000000;
-  const rand = Math.floor(Math.random() * 1000);
-  return (ts * 1000 + rand) >>> 0;
-};
 type AppPage = 'home' | 'games' | 'docs';
 type CreateMode = 'create' | 'import' | 'load';
 
@@ -105,12 +100,15 @@ export default function App() {
 
   const [page, setPage] = useState<AppPage>('games');
   const [gameActive, setGameActive] = useState(false);
-  const [sessionId, setSessionId] = useState(createSessionId);
+  // Allocated by the contract when the session is created; null until then.
+  const [sessionId, setSessionId] = useState<number | null>(null);
 
   // Create-mode form state
   const [createMode, setCreateMode] = useState<CreateMode>('create');
   const [player1Address, setPlayer1Address] = useState(publicKey || '');
   const [player1Points, setPlayer1Points] = useState('0.1');
+  const [player2Address, setPlayer2Address] = useState('');
+  const [difficulty, setDifficulty] = useState<Difficulty>('Normal');
 
   // Auth entry export (Player 1 → Player 2)
   const [exportedXDR, setExportedXDR] = useState<string | null>(null);
@@ -119,7 +117,7 @@ export default function App() {
   // Import-mode state (Player 2)
   const [importXDR, setImportXDR] = useState('');
   const [importP2Points, setImportP2Points] = useState('0.1');
-  const [importParsed, setImportParsed] = useState<{ sessionId: number; player1: string; player1Points: string } | null>(null);
+  const [importParsed, setImportParsed] = useState<{ player1: string; player1Points: string; difficulty: Difficulty } | null>(null);
   const [importError, setImportError] = useState<string | null>(null);
 
   // Load-mode
@@ -137,6 +135,7 @@ export default function App() {
     player2: string;
     player1Points: bigint;
     player2Points: bigint;
+    difficulty: Difficulty;
   } | null>(null);
 
   const shortAddr = (a: string) => a ? `${a.slice(0, 8)}...${a.slice(-4)}` : '—';
@@ -155,8 +154,7 @@ export default function App() {
   };
 
   const handlePrepare = useCallback(async () => {
-    const freshSession = createSessionId();
-    setSessionId(freshSession);
+    setSessionId(null);
     setError(null); setSuccess(null);
     if (!isConnected || !publicKey) {
       setError('Wallet not connected. Please connect your wallet first.');
@@ -164,28 +162,24 @@ export default function App() {
     }
     const p1Points = parsePoints(player1Points);
     if (!p1Points || p1Points <= 0n) { setError('Enter a valid points amount.'); return; }
-
-    // Placeholder P2 for simulation
-    let simulationP2 = 'GDO5KFBDKWAGPP3MC72BGBGMBA3UKYKLRTUUYX3AJLCGRVE2LDSEBDK7';
-    if (simulationP2 === publicKey) {
-      simulationP2 = 'GBD2IS3IQCZV565EMUF6TP74LQ5GFHJDH3GF3YTCF34XHLS7BMK6JATX';
-    }
+    if (!player2Address || player2Address === player1Address) { setError('Enter your opponent\'s address.'); return; }
 
     try {
       setLoading(true);
-      const data = await cubeathonService.prepareStartGame(freshSession, player1Address, p1Points);
+      const data = await cubeathonService.prepareCreateSession(
+        player1Address, player2Address, p1Points, getContractSigner(), 'Survival', difficulty
+      );
       setExportedXDR(data);
-      setSuccess('Game info ready! Copy the code below and send it to Player 2.');
+      setSuccess('Auth entry signed! Copy the code below and send it to Player 2.');
     } catch (err) {
       setError(err instanceof Error ? err.message : 'Prepare failed.');
     } finally {
       setLoading(false);
     }
-  }, [isConnected, publicKey, player1Address, player1Points, sessionId, getContractSigner]);
+  }, [isConnected, publicKey, player1Address, player2Address, player1Points, difficulty, getContractSigner]);
 
   const handleQuickstart = useCallback(async () => {
-    const freshSession = createSessionId();
-    setSessionId(freshSession);
+    setSessionId(null);
     setError(null); setSuccess(null);
     if (!quickstartAvailable) {
       setError('Quickstart requires dev wallets (VITE_DEV_* secrets).');
@@ -193,7 +187,7 @@ export default function App() {
     }
     try {
       setLoading(true);
-      console.warn(`[Cubeathon] Quickstart starting Session: ${freshSession}`);
+      console.warn('[Cubeathon] Quickstart starting');
       await devWalletService.initPlayer(1);
       const p1Addr = devWalletService.getPublicKey();
       const p1Signer = devWalletService.getSigner();
@@ -237,14 +231,18 @@ export default function App() {
       await new Promise(r => setTimeout(r, 3000));
 
       console.info("[Cubeathon] Initializing game transaction...");
-      await cubeathonService.startGame(
-        freshSession, p1Addr, p2Addr, points, points, p2Signer
+      const p1AuthEntry = await cubeathonService.signCreateSessionAuth(
+        p1Addr, p2Addr, points, p1Signer, 'Survival', difficulty
+      );
+      const freshSession = await cubeathonService.createSession(
+        p1Addr, p2Addr, points, points, p1AuthEntry, p2Signer, 'Survival', difficulty
       );
 
       console.info("[Cubeathon] Transaction SUCCESS! Fetching game state...");
       const state = await cubeathonService.getGame(freshSession);
 
       if (!state) throw new Error("Could not fetch game state after creation.");
+      setSessionId(freshSession);
 
       await connectDev(1);
       setActiveGame({
@@ -253,6 +251,7 @@ export default function App() {
         player2: state.player2,
         player1Points: state.p1_points,
         player2Points: state.p2_points,
+        difficulty: state.difficulty[0],
       });
       setSuccess(`Quickstart complete! Session ${freshSession} initialized on-chain.`);
       setGameActive(true);
@@ -262,7 +261,7 @@ export default function App() {
     } finally {
       setLoading(false);
     }
-  }, [quickstartAvailable, connectDev]);
+  }, [quickstartAvailable, connectDev, difficulty]);
 
   const handleImport = useCallback(async () => {
     setError(null); setSuccess(null);
@@ -276,27 +275,33 @@ export default function App() {
       if (parsed.player1 === publicKey) {
         throw new Error('You cannot import your own auth entry (you are Player 1).');
       }
+      if (parsed.player2 !== publicKey) {
+        throw new Error('This auth entry was signed for a different Player 2.');
+      }
       setImportParsed({
-        sessionId: parsed.sessionId,
         player1: parsed.player1,
         player1Points: (Number(parsed.player1Points) / 1e7).toFixed(2),
+        difficulty: parsed.difficulty,
       });
       const signer = getContractSigner();
-      await cubeathonService.startGame(
-        parsed.sessionId, parsed.player1, publicKey, parsed.player1Points, p2Points, signer
+      const newSession = await cubeathonService.createSession(
+        parsed.player1, publicKey, parsed.player1Points, p2Points,
+        parsed.authEntry, signer, parsed.mode, parsed.difficulty
       );
 
-      const state = await cubeathonService.getGame(parsed.sessionId);
+      const state = await cubeathonService.getGame(newSession);
       if (!state) throw new Error("Could not fetch game state.");
+      setSessionId(newSession);
 
       setActiveGame({
-        sessionId: parsed.sessionId,
+        sessionId: newSession,
         player1: state.player1,
         player2: state.player2,
         player1Points: state.p1_points,
         player2Points: state.p2_points,
+        difficulty: state.difficulty[0],
       });
-      setSuccess('Game created on-chain! Starting game…');
+      setSuccess(`Game created on-chain as session ${newSession}! Send this ID to Player 1. Starting game…`);
       setGameActive(true);
     } catch (err) {
       setError(err instanceof Error ? err.message : 'Import failed');
@@ -313,12 +318,14 @@ export default function App() {
       setLoading(true);
       const game = await cubeathonService.getGame(sid);
       if (!game) { setError('Game not found on-chain.'); return; }
+      setSessionId(sid);
       setActiveGame({
         sessionId: sid,
         player1: game.player1,
         player2: game.player2,
         player1Points: game.p1_points,
         player2Points: game.p2_points,
+        difficulty: game.difficulty[0],
       });
       setSuccess('Game found! Loading…');
       setGameActive(true);
@@ -356,11 +363,12 @@ export default function App() {
           <CubeathonGame
             key={`${publicKey}-${activeGame?.sessionId ?? sessionId}`}
             userAddress={publicKey ?? ''}
-            sessionId={activeGame?.sessionId ?? sessionId}
+            sessionId={activeGame?.sessionId ?? sessionId ?? 0}
             player1={activeGame?.player1 ?? player1Address}
             player2={activeGame?.player2 ?? ''}
             availablePoints={activeGame?.player1 === publicKey ? activeGame.player1Points : activeGame?.player2Points ?? 10000000n}
             isOnChain={!!activeGame}
+            sessionDifficulty={activeGame?.difficulty.toLowerCase() as RunDifficulty | undefined}
             onBack={() => setGameActive(false)}
             onStandingsRefresh={() => { }}
             onGameComplete={(w) => {
@@ -478,7 +486,7 @@ export default function App() {
               Survive the obstacles at increasing speed! Highest survival time wins the jackpot.
             </p>
             <p style={{ fontFamily: 'var(--font-mono)', fontSize: '.72rem', color: 'var(--color-ink-muted)', margin: '3px 0 0' }}>
-              Session ID: {sessionId}
+              Session ID: {sessionId ?? '—'}
             </p>
           </div>
           <button onClick={() => setPage('home')} style={{ background: 'linear-gradient(135deg,#e5e7eb,#d1d5db)', border: '1px solid #9ca3af', color: '#374151', padding: '9px 18px', borderRadius: 10, fontWeight: 700, fontSize: '.82rem', cursor: 'pointer', whiteSpace: 'nowrap' }}>
@@ -504,6 +512,17 @@ export default function App() {
             ))}
           </div>
 
+          {createMode === 'create' && (
+            <div style={{ marginBottom: '1.5rem' }}>
+              <label style={lbl}>Difficulty</label>
+              <select value={difficulty} onChange={e => setDifficulty(e.target.value as Difficulty)} style={inp}>
+                <option value="Easy">Easy</option>
+                <option value="Normal">Normal</option>
+                <option value="Hard">Hard</option>
+              </select>
+              <p style={hint}>Both players run every level at this difficulty; it is part of your signature.</p>
+            </div>
+          )}
           <div style={{ background: 'linear-gradient(135deg,#fefce8,#fef3c7)', border: '2px solid #fde68a', borderRadius: 14, padding: '14px 18px', marginBottom: '1.5rem', display: 'flex', justifyContent: 'space-between', alignItems: 'center', flexWrap: 'wrap', gap: 12 }}>
             <div>
               <p style={{ fontWeight: 800, fontSize: '.83rem', color: '#78350f', marginBottom: 2 }}>⚡ Quickstart (Dev)</p>
@@ -522,6 +541,10 @@ export default function App() {
                 <input type="text" value={player1Address} onChange={e => setPlayer1Address(e.target.value.trim())} placeholder="G..." style={inp} />
                 <p style={hint}>Pre-filled from connected wallet.</p>
               </div>
+              <div>
+                <label style={lbl}>Opponent Address (Player 2)</label>
+                <input type="text" value={player2Address} onChange={e => setPlayer2Address(e.target.value.trim())} placeholder="G..." style={inp} />
+              </div>
               <div>
                 <label style={lbl}>Your Points</label>
                 <input type="text" value={player1Points} onChange={e => setPlayer1Points(e.target.value)} placeholder="0.1" style={inp} />
@@ -529,10 +552,10 @@ export default function App() {
               </div>
               <div style={infoBox}>
                 <p style={{ fontSize: '.72rem', fontWeight: 600, color: '#1e40af' }}>
-                  ℹ️ Player 2 will specify their own address and points when they import your auth entry. You only need to prepare and export your signature.
+                  ℹ️ Your signature covers your opponent, your points and the game rules. Player 2 picks their own points when they import it.
                 </p>
               </div>
-              <p style={hint}>Session ID: {sessionId}</p>
+              <p style={hint}>The contract assigns the session ID when Player 2 submits.</p>
               <button onClick={handlePrepare} disabled={loading || !isConnected} style={{ ...bigBtn('linear-gradient(135deg,#a855f7,#ec4899)') }}>
                 {loading ? 'Preparing…' : 'Prepare & Export Auth Entry'}
               </button>
@@ -552,24 +575,24 @@ export default function App() {
               </div>
               <div style={infoBox}>
                 <p style={{ fontSize: '.72rem', color: '#1e40af', fontWeight: 600 }}>
-                  ⏳ Waiting for Player 2 to import and submit… Once they do, start the game below.
+                  ⏳ Waiting for Player 2 to import and submit… Once they do, they get the session ID; load it below to start.
                 </p>
               </div>
-              <button onClick={() => setGameActive(true)} style={{ ...bigBtn('linear-gradient(135deg,#10b981,#059669)') }}>▶ Enter Game (Player 1)</button>
+              <button onClick={() => { setCreateMode('load'); setExportedXDR(null); }} style={{ ...bigBtn('linear-gradient(135deg,#10b981,#059669)') }}>▶ Load Game (Player 1)</button>
             </div>
           )}
           {createMode === 'import' && (
             <div style={{ display: 'flex', flexDirection: 'column', gap: '1.25rem' }}>
               <div>
                 <label style={lbl}>Auth Entry XDR (from Player 1)</label>
-                <textarea value={importXDR} onChange={e => { setImportXDR(e.target.value); setImportError(null); setImportParsed(null); try { if (e.target.value.trim()) setImportParsed((() => { const p = cubeathonService.parseAuthEntry(e.target.value.trim()); return { sessionId: p.sessionId, player1: p.player1, player1Points: (Number(p.player1Points) / 1e7).toFixed(2) }; })()); } catch { setImportError('Invalid XDR. Paste the exact string from Player 1.'); } }} placeholder="Paste XDR auth entry here…" rows={5} style={{ ...inp, fontFamily: 'var(--font-mono)', fontSize: '.65rem', resize: 'vertical' }} />
+                <textarea value={importXDR} onChange={e => { setImportXDR(e.target.value); setImportError(null); setImportParsed(null); try { if (e.target.value.trim()) setImportParsed((() => { const p = cubeathonService.parseAuthEntry(e.target.value.trim()); return { player1: p.player1, player1Points: (Number(p.player1Points) / 1e7).toFixed(2), difficulty: p.difficulty }; })()); } catch { setImportError('Invalid XDR. Paste the exact string from Player 1.'); } }} placeholder="Paste XDR auth entry here…" rows={5} style={{ ...inp, fontFamily: 'var(--font-mono)', fontSize: '.65rem', resize: 'vertical' }} />
                 {importError && <p style={{ fontSize: '.72rem', color: '#ef4444', fontWeight: 600, marginTop: 4 }}>{importError}</p>}
               </div>
               {importParsed && (
                 <div style={infoBox}>
                   <p style={{ fontSize: '.72rem', fontWeight: 700, color: '#1e40af', marginBottom: 4 }}>✅ Auth entry parsed:</p>
                   <p style={{ fontSize: '.72rem', color: '#1e40af' }}>Player 1: <code>{importParsed.player1.slice(0, 10)}…</code></p>
-                  <p style={{ fontSize: '.72rem', color: '#1e40af' }}>Session: <code>{importParsed.sessionId}</code> · Points: <code>{importParsed.player1Points}</code></p>
+                  <p style={{ fontSize: '.72rem', color: '#1e40af' }}>Points: <code>{importParsed.player1Points}</code> · Difficulty: <code>{importParsed.difficulty}</code></p>
                 </div>
               )}
               <div>
@@ -585,7 +608,7 @@ export default function App() {
             <div style={{ display: 'flex', flexDirection: 'column', gap: '1.25rem' }}>
               <div>
                 <label style={lbl}>Session ID</label>
-                <input type="text" value={loadSessionId} onChange={e => setLoadSessionId(e.target.value)} placeholder={String(sessionId)} style={inp} />
+                <input type="text" value={loadSessionId} onChange={e => setLoadSessionId(e.target.value)} placeholder={sessionId === null ? 'Session ID' : String(sessionId)} style={inp} />
               </div>
               <button onClick={handleLoad} disabled={loading || !isConnected} style={{ ...bigBtn('linear-gradient(135deg,#10b981,#059669)') }}>
                 {loading ? 'Loading…' : 'Load Game →'}
@@ -2,14 +2,15 @@
  * Cubeathon Leaderboard
  *
  * Shows:
- *  - "Hall of Fame" tab: players who completed ALL 3 levels (from on-chain)
+ *  - "Hall of Fame" tab: the combined board of this session's mode and
+ *    difficulty in the current season (from on-chain)
  *      - Every run is shown separately (so a player appears twice if they
  *        finished in 10s and later in 9s – both entries shown)
  *  - Level 1 / Level 2 / Level 3 tabs: pulled from the current game state
  *      Local level times (in-progress) shown while waiting for tx confirmation.
  */
 import { useState, useEffect, useCallback } from "react";
-import { cubeathonService, type LeaderboardEntry } from "../services/cubeathonService";
+import { cubeathonService, type Difficulty, type LeaderboardEntry, type PlayerProgress } from "../services/cubeathonService";
 
 interface LeaderboardProps {
     sessionId: number;
@@ -22,10 +23,14 @@ const MS = (ms: bigint | number) => (Number(ms) / 1000).toFixed(2) + "s";
 const shortAddr = (a: string) => `${a.slice(0, 6)}…${a.slice(-4)}`;
 const medal = (i: number) => i === 0 ? "🥇" : i === 1 ? "🥈" : i === 2 ? "🥉" : `#${i + 1}`;
 
+const totalTime = (progress: PlayerProgress): bigint =>
+    progress.levels.reduce((sum, level) => sum + BigInt(level.time_ms), 0n);
+
 export function CubeathonLeaderboard({
     sessionId, player1, player2, onClose
 }: LeaderboardProps) {
     const [hallOfFame, setHallOfFame] = useState<LeaderboardEntry[]>([]);
+    const [boardDifficulty, setBoardDifficulty] = useState<Difficulty | null>(null);
     const [sessionStats, setSessionStats] = useState<{ player1: string, player2: string, p1Time: bigint, p2Time: bigint } | null>(null);
     const [loading, setLoading] = useState(true);
     const [lastRefresh, setLastRefresh] = useState<Date | null>(null);
@@ -33,17 +38,18 @@ export function CubeathonLeaderboard({
     const refresh = useCallback(async () => {
         setLoading(true);
         try {
-            const [lb, game] = await Promise.all([
-                cubeathonService.getLeaderboard(),
-                cubeathonService.getGame(sessionId)
-            ]);
+            const game = await cubeathonService.getGame(sessionId);
+            const lb = game
+                ? await cubeathonService.getLeaderboard(game.mode[0], game.difficulty[0])
+                : await cubeathonService.getLeaderboard();
             setHallOfFame(lb);
+            setBoardDifficulty(game ? game.difficulty[0] : "Normal");
             if (game) {
                 setSessionStats({
                     player1: game.player1,
                     player2: game.player2,
-                    p1Time: game.p1_progress.max_time_ms,
-                    p2Time: game.p2_progress.max_time_ms,
+                    p1Time: totalTime(game.p1_progress),
+                    p2Time: totalTime(game.p2_progress),
                 });
             }
             setLastRefresh(new Date());
@@ -126,7 +132,7 @@ export function CubeathonLeaderboard({
                 {/* Global Hall of Fame (All Time) */}
                 <div>
                     <p style={{ fontSize: '.75rem', fontWeight: 800, color: '#94a3b8', textTransform: 'uppercase', letterSpacing: '.12em', marginBottom: '0.75rem' }}>
-                        Global Hall of Fame
+                        Global Hall of Fame{boardDifficulty && ` · ${boardDifficulty}`}
                     </p>
                     {loading ? <LoadingRow /> : hallOfFame.length === 0 ? (
                         <EmptyState icon="🏜️" title="No records yet" subtitle="Survive the obstacles to appear here!" />
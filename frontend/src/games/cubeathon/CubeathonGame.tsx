@@ -1,5 +1,5 @@
 import { useState, useEffect, useRef, useCallback } from 'react';
-import { cubeathonService } from '../../services/cubeathonService';
+import { cubeathonService, type Difficulty as BoardDifficulty } from '../../services/cubeathonService';
 import { useWallet } from '../../hooks/useWallet';
 import { CubeathonLeaderboard } from '../../components/CubeathonLeaderboard';
 import type { LeaderboardEntry } from '../../services/cubeathonService';
@@ -34,6 +34,9 @@ interface Wall { worldY: number; gapX: number; size: number; }
 
 export type Difficulty = 'easy' | 'normal' | 'hard';
 
+const DIFFICULTY_COLOR: Record<Difficulty, string> = { easy: '#10b981', normal: '#3b82f6', hard: '#ef4444' };
+const BOARD_DIFFICULTY: Record<Difficulty, BoardDifficulty> = { easy: 'Easy', normal: 'Normal', hard: 'Hard' };
+
 export interface CubeathonGameProps {
     userAddress: string;
     sessionId: number;
@@ -41,6 +44,7 @@ export interface CubeathonGameProps {
     player2: string;
     availablePoints: bigint;
     isOnChain?: boolean;   // true if a real on-chain session was started
+    sessionDifficulty?: Difficulty; // difficulty the on-chain session was started with; runs are locked to it
     onBack: () => void;
     onStandingsRefresh: () => void;
     onGameComplete: (winnerAddr: string) => void;
@@ -50,7 +54,7 @@ export interface CubeathonGameProps {
 // ─────────────────────────────────────────────────────────
 export function CubeathonGame({
     userAddress, sessionId, player1, player2,
-    isOnChain = false,
+    isOnChain = false, sessionDifficulty,
     onBack, onStandingsRefresh
 }: CubeathonGameProps) {
     const { getContractSigner } = useWallet();
@@ -59,8 +63,8 @@ export function CubeathonGame({
 
     const [leaderboard, setLeaderboard] = useState<LeaderboardEntry[]>([]);
     const [showLeaderboard, setShowLeaderboard] = useState(false);
-    const [difficulty, setDifficulty] = useState<Difficulty>('normal');
-    const difficultyRef = useRef<Difficulty>('normal');
+    const [difficulty, setDifficulty] = useState<Difficulty>(sessionDifficulty ?? 'normal');
+    const difficultyRef = useRef<Difficulty>(sessionDifficulty ?? 'normal');
 
     const [zkProof, setZkProof] = useState<{ hash: string; timeMs: number } | null>(null);
     const [finishing, setFinishing] = useState(false);
@@ -89,9 +93,9 @@ export function CubeathonGame({
     const [levelTime, setLevelTime] = useState(0);
 
     const refreshLeaderboard = useCallback(async () => {
-        const board = await cubeathonService.getLeaderboard();
+        const board = await cubeathonService.getLeaderboard('Survival', BOARD_DIFFICULTY[difficulty]);
         setLeaderboard(board);
-    }, []);
+    }, [difficulty]);
 
     useEffect(() => { refreshLeaderboard(); }, [refreshLeaderboard]);
 
@@ -252,7 +256,7 @@ export function CubeathonGame({
         try {
             const runner = await getContractSigner();
             await cubeathonService.submitScore(
-                sessionId, userAddress, BigInt(Math.floor(timeMs)), runner, new Uint8Array(0), hashBytes
+                sessionId, userAddress, 1, BigInt(Math.floor(timeMs)), runner, new Uint8Array(0)
             );
             refreshLeaderboard();
         } catch (err) {
@@ -437,11 +441,13 @@ export function CubeathonGame({
 
                     {phase === 'picking' && (
                         <div style={{ position: 'absolute', inset: 0, display: 'flex', flexDirection: 'column', alignItems: 'center', justifyContent: 'center', background: 'rgba(2,10,34,0.92)' }}>
-                            <h3 style={{ color: 'white', marginBottom: '2rem' }}>SELECT DIFFICULTY</h3>
+                            <h3 style={{ color: 'white', marginBottom: '2rem' }}>{sessionDifficulty ? 'SESSION DIFFICULTY' : 'SELECT DIFFICULTY'}</h3>
                             <div style={{ display: 'flex', flexDirection: 'column', gap: 12, width: 240 }}>
-                                <button onClick={() => startGame('easy')} style={{ background: '#10b981', color: 'white', padding: 14, borderRadius: 12, border: 'none', fontWeight: 800 }}>EASY</button>
-                                <button onClick={() => startGame('normal')} style={{ background: '#3b82f6', color: 'white', padding: 14, borderRadius: 12, border: 'none', fontWeight: 800 }}>NORMAL</button>
-                                <button onClick={() => startGame('hard')} style={{ background: '#ef4444', color: 'white', padding: 14, borderRadius: 12, border: 'none', fontWeight: 800 }}>HARD</button>
+                                {(Object.keys(DIFFICULTY_COLOR) as Difficulty[])
+                                    .filter(d => !sessionDifficulty || d === sessionDifficulty)
+                                    .map(d => (
+                                        <button key={d} onClick={() => startGame(d)} style={{ background: DIFFICULTY_COLOR[d], color: 'white', padding: 14, borderRadius: 12, border: 'none', fontWeight: 800 }}>{d.toUpperCase()}</button>
+                                    ))}
                             </div>
                         </div>
                     )}
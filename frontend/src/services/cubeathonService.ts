@@ -3,10 +3,12 @@
  */
 import {
     Contract,
+    Operation,
     TransactionBuilder,
     BASE_FEE,
     xdr,
     Address,
+    authorizeInvocation,
     nativeToScVal,
     scValToNative,
 } from "@stellar/stellar-sdk";
@@ -14,61 +16,178 @@ import { rpc as StellarRpc } from "@stellar/stellar-sdk";
 import { Buffer } from "buffer";
 import {
     CUBEATHON_CONTRACT_ID,
+    DEFAULT_AUTH_TTL_MINUTES,
     NETWORK_PASSPHRASE,
     RPC_URL,
 } from "../utils/constants";
+import { calculateValidUntilLedger } from "../utils/ledgerUtils";
 import type { ContractSigner } from "../types/signer";
 
 const makeServer = () => new StellarRpc.Server(RPC_URL);
 
+/** Rule set a session is played under; the endless runner plays Survival. */
+export type GameMode = "Race" | "Survival";
+
+/** Session difficulty; each difficulty has its own leaderboards. */
+export type Difficulty = "Easy" | "Normal" | "Hard";
+
+/** Session lifecycle as reported by `get_game`. */
+export type GameStatus = "Pending" | "Active" | "AwaitingReveal" | "Finalized" | "Cancelled" | "Expired";
+
+// Unit enum variants are encoded as a single-symbol vec.
+const gameModeScVal = (mode: GameMode) => xdr.ScVal.scvVec([xdr.ScVal.scvSymbol(mode)]);
+const difficultyScVal = (difficulty: Difficulty) => xdr.ScVal.scvVec([xdr.ScVal.scvSymbol(difficulty)]);
+
+/**
+ * `create_session` as one player authorizes it. The contract checks each
+ * player's auth against (opponent, ownPoints, mode, difficulty, ownCommit)
+ * rather than the call's own arguments; the session id is allocated on-chain
+ * and is not part of the signature.
+ */
+const createSessionInvocation = (
+    opponent: string,
+    ownPoints: bigint,
+    mode: GameMode,
+    difficulty: Difficulty,
+) => new xdr.SorobanAuthorizedInvocation({
+    function: xdr.SorobanAuthorizedFunction.sorobanAuthorizedFunctionTypeContractFn(
+        new xdr.InvokeContractArgs({
+            contractAddress: new Address(CUBEATHON_CONTRACT_ID).toScAddress(),
+            functionName: "create_session",
+            args: [
+                nativeToScVal(opponent, { type: "address" }),
+                nativeToScVal(ownPoints, { type: "i128" }),
+                gameModeScVal(mode),
+                difficultyScVal(difficulty),
+                xdr.ScVal.scvVoid(), // own_commit: contract-drawn seed
+            ],
+        }),
+    ),
+    subInvocations: [],
+});
+
+/** Session terms and player 1's signed auth entry, as exported to player 2. */
+export interface SessionOffer {
+    player1: string;
+    player2: string;
+    player1Points: bigint;
+    mode: GameMode;
+    difficulty: Difficulty;
+    authEntry: string; // player 1's SorobanAuthorizationEntry, base64 XDR
+}
+
 export interface LeaderboardEntry {
     session_id: number;
     player: string;
     time_ms: bigint;
 }
 
+export interface LevelResult {
+    time_ms: bigint;
+    verified: boolean;
+}
+
+export interface PlayerProgress {
+    levels: LevelResult[]; // best run per cleared level, index = level - 1
+}
+
 export interface GameState {
     player1: string;
     player2: string;
     p1_points: bigint;
     p2_points: bigint;
-    p1_progress: { max_time_ms: bigint };
-    p2_progress: { max_time_ms: bigint };
+    mode: [GameMode];
+    difficulty: [Difficulty];
+    p1_progress: PlayerProgress;
+    p2_progress: PlayerProgress;
     winner: string | null;
+    status: [GameStatus];
+    draw: boolean;
+    forfeit: boolean; // won by claim_forfeit against a no-show
+    seed?: Buffer; // on-chain track seed, bound into every proof; unset until commit-reveal completes
 }
 
 export class CubeathonService {
     /**
-     * Start a game session. 
-     * In the simplified contract, only the submitter (player2) needs to sign.
+     * Sign player 1's authorization for `create_session`, valid for
+     * DEFAULT_AUTH_TTL_MINUTES. Returns the signed auth entry as base64 XDR,
+     * to be passed to `createSession` by player 2.
      */
-    async startGame(
-        sessionId: number,
+    async signCreateSessionAuth(
+        player1: string,
+        player2: string,
+        p1Points: bigint,
+        signer: ContractSigner,
+        mode: GameMode = "Survival",
+        difficulty: Difficulty = "Normal",
+    ): Promise<string> {
+        const validUntil = await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+        const entry = await authorizeInvocation(
+            async (preimage) => {
+                const { signedAuthEntry, error } = await signer.signAuthEntry!(preimage.toXDR("base64"), {
+                    networkPassphrase: NETWORK_PASSPHRASE,
+                    address: player1,
+                });
+                if (error) throw new Error(error.message);
+                return Buffer.from(signedAuthEntry, "base64");
+            },
+            validUntil,
+            createSessionInvocation(player2, p1Points, mode, difficulty),
+            player1,
+            NETWORK_PASSPHRASE,
+        );
+        return entry.toXDR("base64");
+    }
+
+    /**
+     * Start a game session and return the session id the contract allocated.
+     * The contract requires both players' authorization. This submits as
+     * player2, whose authorization comes from the transaction signature;
+     * player1's comes from `player1AuthEntry`, signed by
+     * `signCreateSessionAuth` over the same points, mode and difficulty.
+     */
+    async createSession(
         player1: string,
         player2: string,
         p1Points: bigint,
         p2Points: bigint,
+        player1AuthEntry: string,
         signer: ContractSigner,
-    ): Promise<void> {
+        mode: GameMode = "Survival",
+        difficulty: Difficulty = "Normal",
+    ): Promise<number> {
         const s = makeServer();
         const account = await s.getAccount(player2);
-        const contract = new Contract(CUBEATHON_CONTRACT_ID);
 
         const args = [
-            nativeToScVal(sessionId, { type: "u32" }),
             nativeToScVal(player1, { type: "address" }),
             nativeToScVal(player2, { type: "address" }),
             nativeToScVal(p1Points, { type: "i128" }),
             nativeToScVal(p2Points, { type: "i128" }),
+            gameModeScVal(mode),
+            difficultyScVal(difficulty),
+            xdr.ScVal.scvVoid(), // seed_commitments: contract-drawn seed
         ];
 
         const tx = new TransactionBuilder(account, {
             fee: (600000).toString(), // High fee for fast testnet inclusion
             networkPassphrase: NETWORK_PASSPHRASE
         })
-            .addOperation(contract.call("start_game", ...args))
+            .addOperation(Operation.invokeContractFunction({
+                contract: CUBEATHON_CONTRACT_ID,
+                function: "create_session",
+                args,
+                auth: [
+                    xdr.SorobanAuthorizationEntry.fromXDR(player1AuthEntry, "base64"),
+                    new xdr.SorobanAuthorizationEntry({
+                        credentials: xdr.SorobanCredentials.sorobanCredentialsSourceAccount(),
+                        rootInvocation: createSessionInvocation(player1, p2Points, mode, difficulty),
+                    }),
+                ],
+            }))
             .setTimeout(60).build();
 
+        // With auth attached, simulation enforces player 1's signature.
         const sim = await s.simulateTransaction(tx);
         if (StellarRpc.Api.isSimulationError(sim)) {
             console.error("[Cubeathon] Simulation failed:", sim.error);
@@ -85,8 +204,8 @@ export class CubeathonService {
 
         const resp = await s.sendTransaction(TransactionBuilder.fromXDR(signedTxXdr, NETWORK_PASSPHRASE));
         if (resp.status === "ERROR") {
-            console.error("[Cubeathon] startGame FAILED:", resp);
-            throw new Error(`Start game failed: ${resp.status}`);
+            console.error("[Cubeathon] createSession FAILED:", resp);
+            throw new Error(`Create session failed: ${resp.status}`);
         }
 
         // Poll for completion
@@ -95,7 +214,7 @@ export class CubeathonService {
             await new Promise(r => setTimeout(r, 2000));
             try {
                 const t = await s.getTransaction(resp.hash);
-                if (t.status === "SUCCESS") return;
+                if (t.status === "SUCCESS") return scValToNative(t.returnValue!) as number;
                 if (t.status === "FAILED") {
                     console.error("[Cubeathon] Transaction hash FAILED on-chain:", resp.hash);
                     throw new Error("Contract execution failed on-chain.");
@@ -136,10 +255,11 @@ export class CubeathonService {
     async submitScore(
         sessionId: number,
         player: string,
+        level: number,
         timeMs: bigint,
         signer: ContractSigner,
         proof?: Uint8Array,
-        journalHash?: Uint8Array
+        nonce?: Uint8Array
     ): Promise<boolean> {
         const s = makeServer();
         const account = await s.getAccount(player);
@@ -147,14 +267,18 @@ export class CubeathonService {
 
         // Use placeholder proof if none provided for testing
         const proofBytes = proof ? Buffer.from(proof) : Buffer.alloc(0);
-        const journalBytes = journalHash ? Buffer.from(journalHash) : Buffer.alloc(32);
+        // The contract recomputes the journal from (seed, player, time, nonce).
+        // The nonce must be a canonical BN254 scalar, so clear its top bits.
+        const nonceBytes = nonce ? Buffer.from(nonce) : Buffer.from(crypto.getRandomValues(new Uint8Array(32)));
+        nonceBytes[0] &= 0x1f;
 
         const args = [
             nativeToScVal(sessionId, { type: "u32" }),
             nativeToScVal(player, { type: "address" }),
+            nativeToScVal(level, { type: "u32" }),
             nativeToScVal(timeMs, { type: "u64" }),
+            xdr.ScVal.scvBytes(nonceBytes),
             xdr.ScVal.scvBytes(proofBytes),
-            xdr.ScVal.scvBytes(journalBytes),
         ];
 
         const tx = new TransactionBuilder(account, { fee: (500000).toString(), networkPassphrase: NETWORK_PASSPHRASE })
@@ -219,50 +343,74 @@ export class CubeathonService {
         return "Transaction pending...";
     }
 
-    async getLeaderboard() {
+    /**
+     * Top `limit` entries of the current season's board for `mode` and
+     * `difficulty`. `level` 0 is the combined-time board; 1 and up hold
+     * the best single runs on that level.
+     */
+    async getLeaderboard(
+        mode: GameMode = "Survival",
+        difficulty: Difficulty = "Normal",
+        level = 0,
+        limit = 50,
+    ): Promise<LeaderboardEntry[]> {
         try {
-            const s = makeServer();
-            const source = import.meta.env.VITE_DEV_PLAYER1_ADDRESS || "GBD2IS3IQCZV565EMUF6TP74LQ5GFHJDH3GF3YTCF34XHLS7BMK6JATX";
-            const account = await s.getAccount(source);
-            const contract = new Contract(CUBEATHON_CONTRACT_ID);
-            const tx = new TransactionBuilder(account, { fee: BASE_FEE, networkPassphrase: NETWORK_PASSPHRASE })
-                .addOperation(contract.call("get_leaderboard"))
-                .setTimeout(30).build();
-
-            const sim = await s.simulateTransaction(tx);
-            const retval = (sim as any).result?.retval ?? (sim as any).returnValue;
-            return retval ? scValToNative(retval) : [];
+            const board = await this.view(
+                "get_current_board", gameModeScVal(mode), difficultyScVal(difficulty), nativeToScVal(level, { type: "u32" }),
+            );
+            if (!board) return [];
+            const page = await this.view(
+                "get_leaderboard_page", board, nativeToScVal(0, { type: "u32" }), nativeToScVal(limit, { type: "u32" }),
+            );
+            return page ? scValToNative(page).entries : [];
         } catch {
             return [];
         }
     }
 
-    // Keep parseAuthEntry for UI compatibility, even if used for simpler data transfer now
-    parseAuthEntry(data: string) {
-        try {
-            // If it's the simplified JSON format
-            const decoded = JSON.parse(Buffer.from(data, "base64").toString());
-            return {
-                sessionId: decoded.sessionId,
-                player1: decoded.player1,
-                player1Points: BigInt(decoded.p1Points || "1000000")
-            };
-        } catch {
-            // Fallback to minimal identity
-            return { sessionId: 0, player1: data, player1Points: 1000000n };
-        }
+    /** Simulate a read-only call and return its result, or null on failure. */
+    private async view(method: string, ...args: xdr.ScVal[]): Promise<xdr.ScVal | null> {
+        const s = makeServer();
+        const source = import.meta.env.VITE_DEV_PLAYER1_ADDRESS || "GBD2IS3IQCZV565EMUF6TP74LQ5GFHJDH3GF3YTCF34XHLS7BMK6JATX";
+        const account = await s.getAccount(source);
+        const contract = new Contract(CUBEATHON_CONTRACT_ID);
+        const tx = new TransactionBuilder(account, { fee: BASE_FEE, networkPassphrase: NETWORK_PASSPHRASE })
+            .addOperation(contract.call(method, ...args))
+            .setTimeout(30).build();
+
+        const sim = await s.simulateTransaction(tx);
+        if (StellarRpc.Api.isSimulationError(sim)) return null;
+        return (sim as any).result?.retval ?? (sim as any).returnValue ?? null;
+    }
+
+    /** Decode an export from `prepareCreateSession`; throws if it is not one. */
+    parseAuthEntry(data: string): SessionOffer {
+        const decoded = JSON.parse(Buffer.from(data, "base64").toString());
+        if (!decoded.authEntry) throw new Error("Missing Player 1 auth entry.");
+        return {
+            player1: decoded.player1,
+            player2: decoded.player2,
+            player1Points: BigInt(decoded.p1Points),
+            mode: decoded.mode,
+            difficulty: decoded.difficulty,
+            authEntry: decoded.authEntry,
+        };
     }
 
     /**
-     * For manual P1 -> P2 flow: P1 just exports their address and points
-     * since they don't need to authorize the transaction directly anymore.
+     * For manual P1 -> P2 flow: P1 signs their `create_session`
+     * authorization and exports it with the session terms it covers.
      */
-    async prepareStartGame(
-        sessionId: number,
+    async prepareCreateSession(
         player1: string,
+        player2: string,
         p1Points: bigint,
+        signer: ContractSigner,
+        mode: GameMode = "Survival",
+        difficulty: Difficulty = "Normal",
     ): Promise<string> {
-        const data = { sessionId, player1, p1Points: p1Points.toString() };
+        const authEntry = await this.signCreateSessionAuth(player1, player2, p1Points, signer, mode, difficulty);
+        const data = { player1, player2, p1Points: p1Points.toString(), mode, difficulty, authEntry };
         return Buffer.from(JSON.stringify(data)).toString("base64");
     }
 }
@@ -306,6 +306,12 @@ for (const contract of contracts) {
 
     let initArgs = `--admin ${adminAddress} --game-hub ${mockGameHubId}`;
 
+    if (contract.packageName === "zk-verifier") {
+      // No on-chain verifiable build of the circuit exists yet, so no circuit is
+      // registered here; add one later with add_circuit.
+      initArgs = `--admin ${adminAddress}`;
+    }
+
     if (contract.packageName === "maze-race" || contract.packageName === "cubeathon") {
       const verifierId = deployed["zk-verifier"];
       if (!verifierId) {
@@ -316,6 +322,13 @@ for (const contract of contracts) {
       initArgs += ` --verifier ${verifierId} --image-id ${imageId}`;
     }
 
+    if (contract.packageName === "cubeathon") {
+      // Required | Optional | Dev. Optional keeps unproven runs off the leaderboard;
+      // Required needs a registered circuit, which this script does not install.
+      const proofPolicy = process.env.CUBEATHON_PROOF_POLICY ?? "Optional";
+      initArgs += ` --proof-policy ${proofPolicy}`;
+    }
+
     const deployResult =
       await $`/usr/local/bin/stellar contract deploy --wasm-hash ${wasmHash} --source-account ${adminSecret} --network ${NETWORK} -- ${initArgs.split(" ")}`.text();
     const contractId = deployResult.trim();